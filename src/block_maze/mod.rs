@@ -0,0 +1,302 @@
+//! This module implements a grid-based maze in which whole cells are blocked.
+//!
+//! # Coordinate System
+//!
+//! The maze uses the same WIDTH × HEIGHT grid and the same `InteriorPosition<WIDTH, HEIGHT>`
+//! cells as the wall maze. Rather than walls between cells, some cells are blocks (drawn
+//! as `#` in the usual pathfinding grid), which can never be entered.
+//!
+//! # Solvability Guarantee
+//!
+//! The maze maintains a guarantee of solvability at all times:
+//! - The start and end positions can never be blocked
+//! - When adding a block, the module verifies the maze remains solvable
+//! - If a block would make the maze unsolvable, the addition is automatically rejected
+
+use crate::{Maze, MazeError, MutSolubleBlockMaze, MutSolubleMaze};
+use crate::BlockMaze as BlockMazeTrait;
+use crate::wall_maze::InteriorPosition;
+#[cfg(test)]
+mod tests;
+
+/// A maze on a grid of cells, some of which are blocked.
+///
+/// The maze consists of a grid of positions with dimensions WIDTH × HEIGHT,
+/// a start position, an end position, and a set of blocked cells. The maze
+/// guarantees that there is always a path from the start to the end position.
+///
+/// Two mazes with the same blocks compare and hash equal regardless of the order in which
+/// the blocks were added.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockMaze<const WIDTH: usize, const HEIGHT: usize> {
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    /// Whether each cell is blocked, indexed by `y * WIDTH + x`.
+    blocked: Vec<bool>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> BlockMaze<WIDTH, HEIGHT> {
+    /// Creates a new `BlockMaze<WIDTH, HEIGHT>` with no blocks and the specified start and end positions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start and end positions are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::block_maze::BlockMaze;
+    /// use maze_solver::wall_maze::InteriorPosition;
+    ///
+    /// let start = InteriorPosition::<5, 5>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<5, 5>::new(4, 4).unwrap();
+    ///
+    /// assert!(BlockMaze::<5, 5>::new(start, end).is_ok());
+    /// assert!(BlockMaze::<5, 5>::new(start, start).is_err());
+    /// ```
+    pub fn new(start: InteriorPosition<WIDTH, HEIGHT>, end: InteriorPosition<WIDTH, HEIGHT>) -> Result<Self, MazeError> {
+        if start == end {
+            return Err(MazeError::StartIsEnd { x: start.get_x(), y: start.get_y() });
+        }
+        Ok(Self {
+            start,
+            end,
+            blocked: vec![false; WIDTH * HEIGHT],
+        })
+    }
+
+    /// Creates a new `BlockMaze<WIDTH, HEIGHT>` with the specified start, end, and blocks.
+    /// A block given more than once is only blocked once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The start and end positions are the same
+    /// - The start or end position is blocked
+    /// - The maze is not solvable with the given blocks
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::block_maze::BlockMaze;
+    /// use maze_solver::wall_maze::InteriorPosition;
+    ///
+    /// let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    ///
+    /// let blocks = vec![InteriorPosition::new(1, 0).unwrap(), InteriorPosition::new(1, 1).unwrap()];
+    /// assert!(BlockMaze::<3, 3>::from_blocks(start, end, blocks).is_ok());
+    ///
+    /// let sealing_blocks = vec![InteriorPosition::new(1, 0).unwrap(), InteriorPosition::new(0, 1).unwrap()];
+    /// assert!(BlockMaze::<3, 3>::from_blocks(start, end, sealing_blocks).is_err());
+    /// ```
+    pub fn from_blocks(start: InteriorPosition<WIDTH, HEIGHT>, end: InteriorPosition<WIDTH, HEIGHT>, blocks: Vec<InteriorPosition<WIDTH, HEIGHT>>) -> Result<Self, MazeError> {
+        let mut maze = Self::new(start, end)?;
+        for block in blocks {
+            maze.blocked[Self::index(block)] = true;
+        }
+        if let Some(&pos) = [start, end].iter().find(|&&pos| maze.is_blocked(pos)) {
+            Err(MazeError::BlockOnStartOrEnd { x: pos.get_x(), y: pos.get_y() })
+        } else if !maze.solveable() {
+            Err(MazeError::Unsolvable)
+        } else {
+            Ok(maze)
+        }
+    }
+
+    fn index(pos: InteriorPosition<WIDTH, HEIGHT>) -> usize {
+        pos.get_y() * WIDTH + pos.get_x()
+    }
+
+    /// Returns `true` if the given position is blocked.
+    pub fn is_blocked(&self, pos: InteriorPosition<WIDTH, HEIGHT>) -> bool {
+        self.blocked[Self::index(pos)]
+    }
+
+    /// Returns the blocked positions of the maze, row by row.
+    pub fn blocks(&self) -> Vec<InteriorPosition<WIDTH, HEIGHT>> {
+        (0..HEIGHT)
+            .flat_map(|y| (0..WIDTH).map(move |x| InteriorPosition::new(x, y).unwrap()))
+            .filter(|&pos| self.is_blocked(pos))
+            .collect()
+    }
+
+    /// Finds a path from the start to the end position in the maze.
+    ///
+    /// Returns a vector of positions representing the path, including both start and end positions.
+    /// The path only moves between adjacent positions and never enters a blocked position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no path exists from start to end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::block_maze::BlockMaze;
+    /// use maze_solver::wall_maze::InteriorPosition;
+    ///
+    /// let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<3, 3>::new(2, 0).unwrap();
+    /// let blocks = vec![InteriorPosition::new(1, 0).unwrap(), InteriorPosition::new(1, 1).unwrap()];
+    /// let maze = BlockMaze::<3, 3>::from_blocks(start, end, blocks).unwrap();
+    ///
+    /// // The only way round is along the bottom row
+    /// assert_eq!(maze.solve().unwrap().len(), 7);
+    /// ```
+    pub fn solve(&self) -> Result<Vec<InteriorPosition<WIDTH, HEIGHT>>, MazeError> {
+        BlockMazeTrait::try_solve(self).ok_or(MazeError::Unsolvable)
+    }
+
+    /// Returns whether the maze can be solved from start to end.
+    fn solveable(&self) -> bool {
+        self.solve().is_ok()
+    }
+
+    /// Blocks a position of the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The position is the start or end position
+    /// - The position is already blocked
+    /// - Blocking the position would make the maze unsolvable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::block_maze::BlockMaze;
+    /// use maze_solver::wall_maze::InteriorPosition;
+    ///
+    /// let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    /// let mut maze = BlockMaze::<3, 3>::new(start, end).unwrap();
+    ///
+    /// assert!(maze.add_block(InteriorPosition::new(1, 0).unwrap()).is_ok());
+    /// assert!(maze.add_block(InteriorPosition::new(1, 0).unwrap()).is_err());
+    ///
+    /// // This block would seal off the start
+    /// assert!(maze.add_block(InteriorPosition::new(0, 1).unwrap()).is_err());
+    /// ```
+    pub fn add_block(&mut self, block: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+        if block == self.start || block == self.end {
+            Err(MazeError::BlockOnStartOrEnd { x: block.get_x(), y: block.get_y() })
+        } else if self.is_blocked(block) {
+            Err(MazeError::BlockAlreadyExists { x: block.get_x(), y: block.get_y() })
+        } else {
+            self.blocked[Self::index(block)] = true;
+            if self.solveable() {
+                Ok(())
+            } else {
+                self.blocked[Self::index(block)] = false;
+                Err(MazeError::BlockWouldMakeUnsolvable { x: block.get_x(), y: block.get_y() })
+            }
+        }
+    }
+
+    /// Unblocks a blocked position of the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position is not blocked.
+    pub fn remove_block(&mut self, block: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+        if self.is_blocked(block) {
+            self.blocked[Self::index(block)] = false;
+            Ok(())
+        } else {
+            Err(MazeError::BlockNotFound { x: block.get_x(), y: block.get_y() })
+        }
+    }
+
+    /// Changes the start position of the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the new start is the end position, is blocked, or cannot reach the end.
+    pub fn move_start(&mut self, new_start: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+        if new_start == self.end {
+            return Err(MazeError::StartIsEnd { x: new_start.get_x(), y: new_start.get_y() });
+        }
+        let old_start = self.start;
+        self.start = new_start;
+        if self.is_blocked(new_start) || !self.solveable() {
+            self.start = old_start;
+            Err(MazeError::PositionWouldMakeUnsolvable { x: new_start.get_x(), y: new_start.get_y() })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Swaps the start and end positions of the maze.
+    pub fn flip_start_end(&mut self) {
+        (self.start, self.end) = (self.end, self.start);
+    }
+
+    /// Changes the end position of the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the new end is the start position, is blocked, or cannot be reached from the start.
+    pub fn move_end(&mut self, new_end: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+        self.flip_start_end();
+        let result = self.move_start(new_end);
+        self.flip_start_end();
+        result
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Maze for BlockMaze<WIDTH, HEIGHT> {
+    type Cell = InteriorPosition<WIDTH, HEIGHT>;
+
+    fn start(&self) -> Self::Cell {
+        self.start
+    }
+
+    fn end(&self) -> Self::Cell {
+        self.end
+    }
+
+    fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell> {
+        cell.adjacent_positions()
+            .into_iter()
+            .filter(|&adj| !self.is_blocked(adj))
+    }
+
+    fn try_solve(&self) -> Option<Vec<Self::Cell>> {
+        BlockMazeTrait::try_solve(self)
+    }
+
+    fn cell_count(&self) -> Option<usize> {
+        Some(WIDTH * HEIGHT)
+    }
+
+    fn cell_index(&self, cell: &Self::Cell) -> Option<usize> {
+        Some(Self::index(*cell))
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> BlockMazeTrait for BlockMaze<WIDTH, HEIGHT> {
+    fn blocks(&self) -> Vec<Self::Cell> {
+        BlockMaze::blocks(self)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> MutSolubleMaze for BlockMaze<WIDTH, HEIGHT> {
+    fn move_start(&mut self, new_start: Self::Cell) -> Result<(), MazeError> {
+        BlockMaze::move_start(self, new_start)
+    }
+
+    fn flip_start_end(&mut self) {
+        BlockMaze::flip_start_end(self)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> MutSolubleBlockMaze for BlockMaze<WIDTH, HEIGHT> {
+    fn add_block(&mut self, block: Self::Cell) -> Result<(), MazeError> {
+        BlockMaze::add_block(self, block)
+    }
+
+    fn remove_block(&mut self, block: Self::Cell) -> Result<(), MazeError> {
+        BlockMaze::remove_block(self, block)
+    }
+}
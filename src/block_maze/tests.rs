@@ -0,0 +1,104 @@
+use super::*;
+use crate::test_util::assert_valid_path;
+
+fn pos<const WIDTH: usize, const HEIGHT: usize>(x: usize, y: usize) -> InteriorPosition<WIDTH, HEIGHT> {
+    InteriorPosition::new(x, y).unwrap()
+}
+
+#[test]
+fn test_block_maze_solvability() {
+    let mut maze = BlockMaze::<3, 3>::new(pos(0, 0), pos(2, 2)).unwrap();
+
+    assert!(maze.add_block(pos(1, 0)).is_ok());
+    assert!(maze.add_block(pos(1, 1)).is_ok());
+    assert!(maze.add_block(pos(0, 0)).is_err());
+    assert!(maze.add_block(pos(2, 2)).is_err());
+
+    // This block would make maze unsolvable
+    assert!(maze.add_block(pos(1, 2)).is_err());
+    assert_eq!(maze.blocks(), vec![pos(1, 0), pos(1, 1)]);
+
+    let path = maze.solve().unwrap();
+    assert_valid_path(&maze, &path);
+    assert!(path.iter().all(|&p| !maze.is_blocked(p)));
+}
+
+#[test]
+fn test_remove_block() {
+    let mut maze = BlockMaze::<3, 3>::new(pos(0, 0), pos(2, 2)).unwrap();
+    maze.add_block(pos(1, 1)).unwrap();
+
+    assert!(maze.remove_block(pos(1, 1)).is_ok());
+    assert!(maze.remove_block(pos(1, 1)).is_err());
+    assert!(maze.blocks().is_empty());
+}
+
+#[test]
+fn test_from_blocks_with_repeated_block() {
+    let maze = BlockMaze::<3, 3>::from_blocks(pos(0, 0), pos(2, 2), vec![pos(1, 1), pos(1, 0)]).unwrap();
+    let mut repeated = BlockMaze::<3, 3>::from_blocks(pos(0, 0), pos(2, 2), vec![pos(1, 0), pos(1, 1), pos(1, 0)]).unwrap();
+    assert_eq!(repeated, maze);
+    assert_eq!(repeated.blocks(), vec![pos(1, 0), pos(1, 1)]);
+
+    // Removing the repeated block unblocks it
+    assert!(repeated.remove_block(pos(1, 0)).is_ok());
+    assert!(!repeated.is_blocked(pos(1, 0)));
+    assert_eq!(repeated.remove_block(pos(1, 0)), Err(MazeError::BlockNotFound { x: 1, y: 0 }));
+}
+
+#[test]
+fn test_move_start_end_respects_blocks() {
+    let blocks = vec![pos(1, 0), pos(1, 1), pos(1, 2), pos(1, 3)];
+    let mut maze = BlockMaze::<4, 5>::from_blocks(pos(0, 0), pos(3, 0), blocks).unwrap();
+
+    assert!(maze.move_start(pos(1, 1)).is_err());
+    assert!(maze.move_start(pos(3, 0)).is_err());
+    assert!(maze.move_end(pos(0, 0)).is_err());
+    assert!(maze.move_start(pos(0, 3)).is_ok());
+    assert!(maze.move_end(pos(2, 0)).is_ok());
+    assert_eq!((maze.start, maze.end), (pos(0, 3), pos(2, 0)));
+
+    // Sealing the start's region off leaves the start where it was
+    maze.add_block(pos(0, 1)).unwrap();
+    assert!(maze.move_start(pos(0, 0)).is_err());
+    assert_eq!(maze.start, pos(0, 3));
+}
+
+#[test]
+fn test_trait_solve_through_blocks() {
+    use crate::{BlockMaze as BlockMazeTrait, MutSolubleBlockMaze};
+
+    let mut maze = BlockMaze::<4, 4>::new(pos(0, 0), pos(3, 3)).unwrap();
+    for block in [pos(1, 0), pos(1, 1), pos(1, 2), pos(3, 1), pos(2, 1)] {
+        MutSolubleBlockMaze::add_block(&mut maze, block).unwrap();
+    }
+    assert!(MutSolubleBlockMaze::add_block(&mut maze, pos(0, 3)).is_err());
+
+    let path = BlockMazeTrait::try_solve(&maze).unwrap();
+    assert_valid_path(&maze, &path);
+    assert_eq!(Maze::try_solve(&maze), Some(path));
+    assert_eq!(BlockMazeTrait::blocks(&maze).len(), 5);
+}
+
+#[test]
+fn test_block_errors_distinguish_cases() {
+    let mut maze = BlockMaze::<3, 3>::new(pos(0, 0), pos(2, 2)).unwrap();
+    maze.add_block(pos(1, 0)).unwrap();
+
+    assert_eq!(maze.add_block(pos(0, 0)), Err(MazeError::BlockOnStartOrEnd { x: 0, y: 0 }));
+    assert_eq!(maze.add_block(pos(1, 0)), Err(MazeError::BlockAlreadyExists { x: 1, y: 0 }));
+    assert_eq!(maze.add_block(pos(0, 1)), Err(MazeError::BlockWouldMakeUnsolvable { x: 0, y: 1 }));
+    assert_eq!(maze.remove_block(pos(2, 0)), Err(MazeError::BlockNotFound { x: 2, y: 0 }));
+    assert_eq!(maze.move_start(pos(2, 2)), Err(MazeError::StartIsEnd { x: 2, y: 2 }));
+}
+
+#[test]
+fn test_block_shortest_solve() {
+    // A depth-first search may wander round the open grid, but the shortest path is direct
+    let mut maze = BlockMaze::<5, 5>::new(pos(0, 0), pos(4, 0)).unwrap();
+    assert_eq!(maze.solve_shortest().unwrap(), (0..5).map(|x| pos(x, 0)).collect::<Vec<_>>());
+
+    maze.add_block(pos(2, 0)).unwrap();
+    assert_eq!(maze.solve_shortest().unwrap().len(), 7);
+    assert!(maze.solve().unwrap().len() >= 7);
+}
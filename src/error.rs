@@ -0,0 +1,104 @@
+//! The error type shared by every fallible operation in the crate.
+
+use std::fmt;
+
+use crate::wall_maze::{Direction, Wall};
+
+/// An error from building, editing or solving a maze.
+///
+/// Each variant carries the coordinates, walls and dimensions involved, so callers can
+/// tell the cases apart and report them without parsing a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MazeError {
+    /// The position (x, y) does not lie within a maze of dimensions width × height.
+    PositionOutOfBounds { x: usize, y: usize, width: usize, height: usize },
+    /// The wall does not lie within the interior of a maze of dimensions width × height,
+    /// either because it is outside the maze or because it would be on its exterior boundary.
+    WallOutOfBounds { wall: Wall, width: usize, height: usize },
+    /// Moving from the position (x, y) in the given direction would leave a maze of dimensions width × height.
+    MoveOutOfBounds { x: usize, y: usize, direction: Direction, width: usize, height: usize },
+    /// The two positions were expected to be adjacent but are not.
+    NotAdjacent { first: (usize, usize), second: (usize, usize) },
+    /// Two positions were expected to be different but are both (x, y).
+    SamePosition { x: usize, y: usize },
+    /// The start and end of a maze would both be the position (x, y).
+    StartIsEnd { x: usize, y: usize },
+    /// A value belonging to a maze of dimensions `found` was used with a maze of dimensions `expected`,
+    /// each given as (width, height).
+    DimensionMismatch { expected: (usize, usize), found: (usize, usize) },
+    /// The wall is already in the maze.
+    WallAlreadyExists { wall: Wall },
+    /// The wall is not in the maze.
+    WallNotFound { wall: Wall },
+    /// Adding the wall would leave no path from the start to the end of the maze.
+    WallWouldMakeUnsolvable { wall: Wall },
+    /// The position (x, y) is already blocked.
+    BlockAlreadyExists { x: usize, y: usize },
+    /// The position (x, y) is not blocked.
+    BlockNotFound { x: usize, y: usize },
+    /// The position (x, y) is the start or end of the maze, which cannot be blocked.
+    BlockOnStartOrEnd { x: usize, y: usize },
+    /// Blocking the position (x, y) would leave no path from the start to the end of the maze.
+    BlockWouldMakeUnsolvable { x: usize, y: usize },
+    /// Moving the start or end of the maze to the position (x, y) would leave no path between them.
+    PositionWouldMakeUnsolvable { x: usize, y: usize },
+    /// There is no path from the start to the end of the maze.
+    Unsolvable,
+}
+
+impl fmt::Display for MazeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MazeError::PositionOutOfBounds { x, y, width, height } => {
+                write!(f, "position ({}, {}) is out of bounds for a maze of dimensions {} × {}", x, y, width, height)
+            },
+            MazeError::WallOutOfBounds { wall, width, height } => {
+                write!(f, "{:?} wall at ({}, {}) is not in the interior of a maze of dimensions {} × {}", wall.get_orientation(), wall.get_x(), wall.get_y(), width, height)
+            },
+            MazeError::MoveOutOfBounds { x, y, direction, width, height } => {
+                write!(f, "cannot move {:?} from position ({}, {}) without leaving a maze of dimensions {} × {}", direction, x, y, width, height)
+            },
+            MazeError::NotAdjacent { first, second } => {
+                write!(f, "positions {:?} and {:?} are not adjacent", first, second)
+            },
+            MazeError::SamePosition { x, y } => {
+                write!(f, "positions are both ({}, {})", x, y)
+            },
+            MazeError::StartIsEnd { x, y } => {
+                write!(f, "start and end positions cannot both be ({}, {})", x, y)
+            },
+            MazeError::DimensionMismatch { expected, found } => {
+                write!(f, "expected a maze of dimensions {} × {} but found one of dimensions {} × {}", expected.0, expected.1, found.0, found.1)
+            },
+            MazeError::WallAlreadyExists { wall } => {
+                write!(f, "{:?} wall at ({}, {}) already exists in the maze", wall.get_orientation(), wall.get_x(), wall.get_y())
+            },
+            MazeError::WallNotFound { wall } => {
+                write!(f, "{:?} wall at ({}, {}) not found in the maze", wall.get_orientation(), wall.get_x(), wall.get_y())
+            },
+            MazeError::WallWouldMakeUnsolvable { wall } => {
+                write!(f, "{:?} wall at ({}, {}) would make the maze unsolvable", wall.get_orientation(), wall.get_x(), wall.get_y())
+            },
+            MazeError::BlockAlreadyExists { x, y } => {
+                write!(f, "position ({}, {}) is already blocked", x, y)
+            },
+            MazeError::BlockNotFound { x, y } => {
+                write!(f, "position ({}, {}) is not blocked", x, y)
+            },
+            MazeError::BlockOnStartOrEnd { x, y } => {
+                write!(f, "position ({}, {}) is the start or end of the maze and cannot be blocked", x, y)
+            },
+            MazeError::BlockWouldMakeUnsolvable { x, y } => {
+                write!(f, "blocking position ({}, {}) would make the maze unsolvable", x, y)
+            },
+            MazeError::PositionWouldMakeUnsolvable { x, y } => {
+                write!(f, "moving the start or end to position ({}, {}) would make the maze unsolvable", x, y)
+            },
+            MazeError::Unsolvable => {
+                write!(f, "no path found from start to end")
+            },
+        }
+    }
+}
+
+impl std::error::Error for MazeError {}
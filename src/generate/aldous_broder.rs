@@ -0,0 +1,55 @@
+//! The Aldous–Broder generator.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, WallMaze};
+
+use super::{check_start_end, index, Carving};
+
+/// Generates a perfect maze with the Aldous–Broder algorithm.
+///
+/// Starting from `start`, the generator walks at random across the whole grid, visited cells
+/// included, and knocks down a wall whenever it steps into a cell for the first time. Like
+/// `wilson`, it picks uniformly from every possible perfect maze, but it is slower, since the
+/// walk takes a long time to find the last few unvisited cells.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::aldous_broder;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let maze = aldous_broder(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+/// assert_eq!(maze.passages().count(), 63);
+/// ```
+pub fn aldous_broder<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    let mut visited = vec![false; WIDTH * HEIGHT];
+    let mut remaining = WIDTH * HEIGHT - 1;
+    let mut current = start;
+    visited[index(start)] = true;
+
+    while remaining > 0 {
+        let neighbours = current.adjacent_positions();
+        let next = neighbours[rng.below(neighbours.len())];
+        if !visited[index(next)] {
+            carving.carve(current, next);
+            visited[index(next)] = true;
+            remaining -= 1;
+        }
+        current = next;
+    }
+    carving.into_maze(start, end)
+}
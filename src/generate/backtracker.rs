@@ -0,0 +1,62 @@
+//! The recursive-backtracker generator.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, WallMaze};
+
+use super::{check_start_end, index, Carving};
+
+/// Generates a perfect maze by a randomized depth-first search, also known as the
+/// recursive backtracker.
+///
+/// Starting from `start`, the search repeatedly moves to a random unvisited neighbour of the
+/// current cell, knocking down the wall between them, and backtracks whenever the current
+/// cell has no unvisited neighbours left. The resulting mazes have long, winding corridors
+/// with few dead ends.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::recursive_backtracker;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let maze = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+///
+/// // A perfect maze on 64 cells has 63 passages
+/// assert_eq!(maze.passages().count(), 63);
+/// assert!(maze.solve().is_ok());
+/// ```
+pub fn recursive_backtracker<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    let mut visited = vec![false; WIDTH * HEIGHT];
+    let mut stack = vec![start];
+    visited[index(start)] = true;
+
+    while let Some(&current) = stack.last() {
+        let unvisited: Vec<_> = current.adjacent_positions()
+            .into_iter()
+            .filter(|&adj| !visited[index(adj)])
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+        } else {
+            let next = unvisited[rng.below(unvisited.len())];
+            carving.carve(current, next);
+            visited[index(next)] = true;
+            stack.push(next);
+        }
+    }
+    carving.into_maze(start, end)
+}
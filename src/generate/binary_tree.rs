@@ -0,0 +1,51 @@
+//! The binary-tree generator.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{Direction, InteriorPosition, WallMaze};
+
+use super::{cells, check_start_end, Carving};
+
+/// Generates a perfect maze with the binary-tree algorithm.
+///
+/// Every cell other than the top-left one knocks down the wall above it or the wall to its left,
+/// chosen at random where it has both. Each cell is decided on its own, so the mazes have a
+/// strong diagonal bias: the top row and left column are always single straight corridors, and
+/// every path towards the top-left corner only ever moves up or left.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::binary_tree;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let maze = binary_tree(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+///
+/// // The path from the bottom-right corner to the top-left never doubles back
+/// assert_eq!(maze.solve().unwrap().len(), 15);
+/// ```
+pub fn binary_tree<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    for cell in cells::<WIDTH, HEIGHT>() {
+        let options: Vec<_> = [Direction::Up, Direction::Left]
+            .into_iter()
+            .filter_map(|direction| cell.shifted_by(direction).ok())
+            .collect();
+        if let Some(&next) = rng.choose(&options) {
+            carving.carve(cell, next);
+        }
+    }
+    carving.into_maze(start, end)
+}
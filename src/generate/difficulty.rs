@@ -0,0 +1,301 @@
+//! Generation of mazes aimed at a target difficulty.
+
+use std::ops::RangeInclusive;
+
+use crate::{Maze, MazeError, MazeWall};
+use crate::rng::MazeRng;
+use crate::solve::{BreadthFirst, Solver};
+use crate::wall_maze::{InteriorPosition, WallMaze};
+
+use super::{check_start_end, wall_between, Generator};
+
+/// Measurements of how hard a maze is to solve.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::MazeMetrics;
+/// use maze_solver::wall_maze::{WallMaze, InteriorPosition, InteriorWall, Orientation};
+///
+/// // A 3 × 2 maze whose only path runs along the top row, down and back along the bottom
+/// let start = InteriorPosition::<3, 2>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<3, 2>::new(0, 1).unwrap();
+/// let walls = vec![
+///     InteriorWall::new(0, 0, Orientation::Horizontal).unwrap(),
+///     InteriorWall::new(1, 0, Orientation::Horizontal).unwrap(),
+/// ];
+/// let maze = WallMaze::from_walls(start, end, walls).unwrap();
+///
+/// let metrics = MazeMetrics::measure(&maze);
+/// assert_eq!(metrics.solution_length, 5);
+/// assert_eq!(metrics.dead_ends, 2);
+/// assert_eq!(metrics.branching_factor, 1.0);
+/// assert_eq!(metrics.turns, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MazeMetrics {
+    /// The number of steps in a shortest path from the start to the end.
+    pub solution_length: usize,
+    /// The number of positions with exactly one open neighbour.
+    pub dead_ends: usize,
+    /// The mean number of ways onward from each position of the shortest path before the end,
+    /// not counting the way back. A maze that is a single corridor has a branching factor of 1.0,
+    /// and every junction passed on the way raises it.
+    pub branching_factor: f64,
+    /// The number of times the shortest path changes direction.
+    pub turns: usize,
+}
+
+impl MazeMetrics {
+    /// Measures a maze, along the shortest path found by `solve::BreadthFirst`.
+    pub fn measure<const WIDTH: usize, const HEIGHT: usize>(maze: &WallMaze<WIDTH, HEIGHT>) -> Self {
+        let path = BreadthFirst.solve(maze).path.unwrap_or_default();
+        let dead_ends = (0..HEIGHT)
+            .flat_map(|y| (0..WIDTH).map(move |x| InteriorPosition::<WIDTH, HEIGHT>::new(x, y).unwrap()))
+            .filter(|&pos| maze.open_neighbours(pos).len() == 1)
+            .count();
+
+        let onward: usize = path.iter().enumerate().take(path.len().saturating_sub(1)).map(|(i, &pos)| {
+            let exits = maze.open_neighbours(pos).len();
+            if i == 0 { exits } else { exits - 1 }
+        }).sum();
+        let branching_factor = if path.len() > 1 { onward as f64 / (path.len() - 1) as f64 } else { 0.0 };
+
+        let turns = path.windows(3).filter(|step| {
+            let (a, b, c) = (step[0], step[1], step[2]);
+            (a.get_x() == b.get_x()) != (b.get_x() == c.get_x())
+        }).count();
+
+        Self { solution_length: path.len().saturating_sub(1), dead_ends, branching_factor, turns }
+    }
+}
+
+/// The ranges each metric of a maze should fall within, for `targeted`.
+///
+/// The default target accepts every maze, so only the metrics of interest need be given.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::DifficultyTarget;
+///
+/// // A solution at least 300 steps long, and at least 60 dead ends
+/// let target = DifficultyTarget {
+///     solution_length: 300..=usize::MAX,
+///     dead_ends: 60..=usize::MAX,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DifficultyTarget {
+    /// The range for `MazeMetrics::solution_length`.
+    pub solution_length: RangeInclusive<usize>,
+    /// The range for `MazeMetrics::dead_ends`.
+    pub dead_ends: RangeInclusive<usize>,
+    /// The range for `MazeMetrics::branching_factor`.
+    pub branching_factor: RangeInclusive<f64>,
+    /// The range for `MazeMetrics::turns`.
+    pub turns: RangeInclusive<usize>,
+}
+
+impl Default for DifficultyTarget {
+    fn default() -> Self {
+        Self {
+            solution_length: 0..=usize::MAX,
+            dead_ends: 0..=usize::MAX,
+            branching_factor: 0.0..=f64::INFINITY,
+            turns: 0..=usize::MAX,
+        }
+    }
+}
+
+impl DifficultyTarget {
+    /// Returns `true` if every metric is within its range.
+    pub fn contains(&self, metrics: &MazeMetrics) -> bool {
+        self.shortfall(metrics) == 0.0
+    }
+
+    /// Returns how far the metrics are from the target, as the sum over the metrics of the
+    /// distance from each to its range, relative to the end of the range it missed.
+    fn shortfall(&self, metrics: &MazeMetrics) -> f64 {
+        fn distance(value: f64, range: (f64, f64)) -> f64 {
+            let (low, high) = range;
+            if value < low {
+                (low - value) / low.max(1.0)
+            } else if value > high {
+                (value - high) / high.max(1.0)
+            } else {
+                0.0
+            }
+        }
+        let bounds = |range: &RangeInclusive<usize>| (*range.start() as f64, *range.end() as f64);
+        distance(metrics.solution_length as f64, bounds(&self.solution_length))
+            + distance(metrics.dead_ends as f64, bounds(&self.dead_ends))
+            + distance(metrics.branching_factor, (*self.branching_factor.start(), *self.branching_factor.end()))
+            + distance(metrics.turns as f64, bounds(&self.turns))
+    }
+}
+
+/// How much work `targeted` may do looking for a maze.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchBudget {
+    /// The number of mazes to generate from scratch. At least one is always generated.
+    pub generations: usize,
+    /// The number of local changes to try on the closest of the generated mazes.
+    pub mutations: usize,
+}
+
+impl Default for SearchBudget {
+    fn default() -> Self {
+        Self { generations: 50, mutations: 500 }
+    }
+}
+
+/// The outcome of `targeted`: the maze closest to the target, and how it measures up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetedMaze<const WIDTH: usize, const HEIGHT: usize> {
+    /// The maze closest to the target.
+    pub maze: WallMaze<WIDTH, HEIGHT>,
+    /// The metrics of the maze.
+    pub metrics: MazeMetrics,
+    /// Whether the metrics are all within the target.
+    pub met: bool,
+    /// The number of mazes generated from scratch.
+    pub generations: usize,
+    /// The number of local changes tried.
+    pub mutations: usize,
+}
+
+/// Searches for a maze whose metrics fall within a target, returning the closest maze found.
+///
+/// Mazes are generated with `generator` until one meets the target or `budget.generations` have
+/// been made. If none meets it, the closest is then changed a step at a time: each step removes a
+/// random wall and closes a random passage on the loop this opens, so a perfect maze stays
+/// perfect, and the change is kept unless it takes the maze further from the target. This goes on
+/// until the target is met or `budget.mutations` steps have been tried.
+///
+/// The search stops as soon as the target is met, and otherwise the closest maze is returned with
+/// `met` set to `false`, so the caller can decide whether it is close enough.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::{targeted, DifficultyTarget, Generator, SearchBudget};
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<40, 40>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<40, 40>::new(39, 39).unwrap();
+/// let target = DifficultyTarget {
+///     solution_length: 300..=usize::MAX,
+///     dead_ends: 60..=usize::MAX,
+///     ..Default::default()
+/// };
+/// let mut rng = Xoshiro256StarStar::new(40);
+///
+/// let found = targeted(Generator::RecursiveBacktracker, start, end, &target, SearchBudget::default(), &mut rng).unwrap();
+/// assert!(found.met);
+/// assert!(found.metrics.solution_length >= 300);
+/// assert!(found.metrics.dead_ends >= 60);
+/// ```
+pub fn targeted<const WIDTH: usize, const HEIGHT: usize>(
+    generator: Generator,
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    target: &DifficultyTarget,
+    budget: SearchBudget,
+    rng: &mut impl MazeRng,
+) -> Result<TargetedMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+
+    let maze = generator.generate(start, end, rng)?;
+    let metrics = MazeMetrics::measure(&maze);
+    let mut best = (target.shortfall(&metrics), maze, metrics);
+    let mut generations = 1;
+    while best.0 > 0.0 && generations < budget.generations {
+        let maze = generator.generate(start, end, rng)?;
+        let metrics = MazeMetrics::measure(&maze);
+        let shortfall = target.shortfall(&metrics);
+        if shortfall < best.0 {
+            best = (shortfall, maze, metrics);
+        }
+        generations += 1;
+    }
+
+    let mut mutations = 0;
+    while best.0 > 0.0 && mutations < budget.mutations {
+        mutations += 1;
+        let mut maze = best.1.clone();
+        if !mutate(&mut maze, rng) {
+            continue;
+        }
+        let metrics = MazeMetrics::measure(&maze);
+        let shortfall = target.shortfall(&metrics);
+        if shortfall <= best.0 {
+            best = (shortfall, maze, metrics);
+        }
+    }
+
+    let (shortfall, maze, metrics) = best;
+    Ok(TargetedMaze { maze, metrics, met: shortfall == 0.0, generations, mutations })
+}
+
+/// Removes a random wall from the maze and adds one across a random passage of the loop this
+/// opens, returning `false` if the maze could not be changed.
+fn mutate<const WIDTH: usize, const HEIGHT: usize>(maze: &mut WallMaze<WIDTH, HEIGHT>, rng: &mut impl MazeRng) -> bool {
+    let walls: Vec<_> = maze.walls().collect();
+    let Some(&wall) = rng.choose(&walls) else {
+        return false;
+    };
+    let [first, second] = wall.surrounding_cells();
+    let around = path_between(maze, first, second);
+    maze.remove_wall(wall).unwrap();
+
+    // Without a way round, removing the wall joins two separate regions and opens no loop
+    let steps: Vec<_> = around.windows(2).map(|step| wall_between(step[0], step[1])).collect();
+    match rng.choose(&steps) {
+        Some(&closed) => maze.add_interior_wall(closed).is_ok(),
+        None => true,
+    }
+}
+
+/// A wall maze seen with other endpoints, so that a solver can find a path between any two of
+/// its positions.
+struct Between<'a, const WIDTH: usize, const HEIGHT: usize> {
+    maze: &'a WallMaze<WIDTH, HEIGHT>,
+    from: InteriorPosition<WIDTH, HEIGHT>,
+    to: InteriorPosition<WIDTH, HEIGHT>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Maze for Between<'_, WIDTH, HEIGHT> {
+    type Cell = InteriorPosition<WIDTH, HEIGHT>;
+
+    fn start(&self) -> Self::Cell {
+        self.from
+    }
+
+    fn end(&self) -> Self::Cell {
+        self.to
+    }
+
+    fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell> {
+        self.maze.open_neighbours(*cell).into_iter()
+    }
+
+    fn try_solve(&self) -> Option<Vec<Self::Cell>> {
+        BreadthFirst.solve(self).path
+    }
+}
+
+/// Returns a shortest path between two positions of the maze, or an empty path if there is none.
+fn path_between<const WIDTH: usize, const HEIGHT: usize>(
+    maze: &WallMaze<WIDTH, HEIGHT>,
+    from: InteriorPosition<WIDTH, HEIGHT>,
+    to: InteriorPosition<WIDTH, HEIGHT>,
+) -> Vec<InteriorPosition<WIDTH, HEIGHT>> {
+    BreadthFirst.solve(&Between { maze, from, to }).path.unwrap_or_default()
+}
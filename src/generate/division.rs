@@ -0,0 +1,129 @@
+//! The recursive division generator.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, InteriorWall, Orientation, WallMaze};
+
+use super::check_start_end;
+
+/// Settings for `recursive_division`.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::DivisionOptions;
+///
+/// // Mostly horizontal walls, leaving long east–west corridors, and rooms at least 3 cells across
+/// let options = DivisionOptions { horizontal_bias: 0.8, min_chamber_size: 3 };
+/// assert_eq!(DivisionOptions::default(), DivisionOptions { horizontal_bias: 0.5, min_chamber_size: 1 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DivisionOptions {
+    /// The probability that a chamber which could be divided either way is divided by a
+    /// horizontal wall rather than a vertical one. Higher values give longer horizontal corridors.
+    pub horizontal_bias: f64,
+    /// The smallest width or height a chamber can be divided into. Chambers that cannot be
+    /// divided without making a smaller one are left as open rooms, so any value above 1 gives a
+    /// maze with rooms rather than a perfect maze. A value of 0 is treated as 1.
+    pub min_chamber_size: usize,
+}
+
+impl Default for DivisionOptions {
+    fn default() -> Self {
+        Self { horizontal_bias: 0.5, min_chamber_size: 1 }
+    }
+}
+
+/// A rectangle of cells yet to be divided, given by its top-left cell and its dimensions.
+#[derive(Debug, Clone, Copy)]
+struct Chamber {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Generates a maze by recursive division, adding walls to an empty maze rather than carving
+/// passages.
+///
+/// The whole grid starts as one open chamber. Each chamber is split in two by a straight wall
+/// with a single gap in it, at a random place and in an orientation chosen using
+/// `options.horizontal_bias`, and the two halves are divided in turn until they are too small
+/// to split. The resulting mazes have long straight walls and a visible hierarchy of chambers.
+///
+/// Since every dividing wall keeps a gap, every cell stays reachable from every other, so the
+/// maze is solvable whatever its start and end. With the default `min_chamber_size` of 1 the
+/// result is a perfect maze.
+///
+/// The walls are collected and passed to `WallMaze::from_walls` in one go. Adding them one at a
+/// time with `WallMaze::add_interior_wall` would give the same maze, but would search for a new
+/// path from start to end each time a wall landed on the current one.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::{recursive_division, DivisionOptions};
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let mut rng = Xoshiro256StarStar::new(1);
+///
+/// let maze = recursive_division(start, end, DivisionOptions::default(), &mut rng).unwrap();
+/// assert_eq!(maze.passages().count(), 63);
+///
+/// // Chambers of at least 2 × 2 leave open rooms, so more passages
+/// let rooms = DivisionOptions { min_chamber_size: 2, ..DivisionOptions::default() };
+/// let maze = recursive_division(start, end, rooms, &mut rng).unwrap();
+/// assert!(maze.passages().count() > 63);
+/// ```
+pub fn recursive_division<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    options: DivisionOptions,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut walls = Vec::new();
+    let min_size = options.min_chamber_size.max(1);
+    let mut chambers = vec![Chamber { x: 0, y: 0, width: WIDTH, height: HEIGHT }];
+
+    while let Some(chamber) = chambers.pop() {
+        let can_divide_horizontally = chamber.height >= 2 * min_size;
+        let can_divide_vertically = chamber.width >= 2 * min_size;
+        let horizontal = match (can_divide_horizontally, can_divide_vertically) {
+            (true, true) => rng.chance(options.horizontal_bias),
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => continue,
+        };
+
+        // Divide along the first axis of (length, breadth): the wall runs along `length`,
+        // and splits `breadth` into two parts of at least `min_size`
+        let (length, breadth) = if horizontal { (chamber.width, chamber.height) } else { (chamber.height, chamber.width) };
+        let before = min_size + rng.below(breadth - 2 * min_size + 1);
+        let gap = rng.below(length);
+        for along in (0..length).filter(|&along| along != gap) {
+            let wall = if horizontal {
+                InteriorWall::new(chamber.x + along, chamber.y + before - 1, Orientation::Horizontal)
+            } else {
+                InteriorWall::new(chamber.x + before - 1, chamber.y + along, Orientation::Vertical)
+            };
+            walls.push(wall?);
+        }
+
+        if horizontal {
+            chambers.push(Chamber { height: before, ..chamber });
+            chambers.push(Chamber { y: chamber.y + before, height: chamber.height - before, ..chamber });
+        } else {
+            chambers.push(Chamber { width: before, ..chamber });
+            chambers.push(Chamber { x: chamber.x + before, width: chamber.width - before, ..chamber });
+        }
+    }
+    WallMaze::from_walls(start, end, walls)
+}
@@ -0,0 +1,207 @@
+//! Eller's generator, which produces a maze one row at a time.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, InteriorWall, Orientation, Wall, WallMaze};
+
+use super::check_start_end;
+
+/// The walls of one row of a maze generated by `EllerRows`.
+///
+/// Each row holds the vertical walls between its own cells and the horizontal walls between
+/// it and the row below. The last row of a maze has no horizontal walls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EllerRow {
+    y: usize,
+    right_walls: Vec<bool>,
+    bottom_walls: Vec<bool>,
+}
+
+impl EllerRow {
+    /// Returns the y-coordinate of this row.
+    pub fn get_y(&self) -> usize {
+        self.y
+    }
+
+    /// Returns `true` if there is a wall to the right of the cell at x-coordinate `x`.
+    ///
+    /// The rightmost cell of the row is never reported as having a wall to its right, as that
+    /// would lie on the exterior boundary of the maze.
+    pub fn has_right_wall(&self, x: usize) -> bool {
+        self.right_walls.get(x).copied().unwrap_or(false)
+    }
+
+    /// Returns `true` if there is a wall below the cell at x-coordinate `x`.
+    ///
+    /// Cells in the last row are never reported as having a wall below them.
+    pub fn has_bottom_wall(&self, x: usize) -> bool {
+        self.bottom_walls.get(x).copied().unwrap_or(false)
+    }
+
+    /// Iterates over the walls of this row: first the horizontal walls below it, then the
+    /// vertical walls within it, each from left to right.
+    pub fn walls(&self) -> impl Iterator<Item = Wall> {
+        let horizontal = self.bottom_walls.iter()
+            .enumerate()
+            .filter(|&(_, &wall)| wall)
+            .map(|(x, _)| Wall::new(x, self.y, Orientation::Horizontal));
+        let vertical = self.right_walls.iter()
+            .enumerate()
+            .filter(|&(_, &wall)| wall)
+            .map(|(x, _)| Wall::new(x, self.y, Orientation::Vertical));
+        horizontal.chain(vertical)
+    }
+}
+
+/// An iterator over the rows of a perfect maze generated by Eller's algorithm, from top to bottom.
+///
+/// Eller's algorithm only needs to know which cells of the current row are already connected
+/// to each other through the rows above, so it uses memory proportional to the width of the
+/// maze however tall it is. Rows can be written out or rendered as they are generated, without
+/// the whole maze ever being held in memory.
+///
+/// For each row but the last, adjacent cells that are not yet connected are joined at random,
+/// and then at least one cell of each connected set is joined at random to the row below, so
+/// that no set is left behind. The last row joins every set that remains.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::EllerRows;
+/// use maze_solver::rng::Xoshiro256StarStar;
+///
+/// let mut rng = Xoshiro256StarStar::new(1);
+/// let mut wall_count = 0;
+/// for row in EllerRows::new(20, 1_000, &mut rng) {
+///     wall_count += row.walls().count();
+/// }
+///
+/// // A perfect maze on 20 000 cells has 19 999 passages out of the 38 980 possible ones
+/// assert_eq!(wall_count, 38_980 - 19_999);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EllerRows<R: MazeRng> {
+    width: usize,
+    height: usize,
+    y: usize,
+    /// The set each cell of the current row belongs to, or `None` for a cell that is not
+    /// connected to the row above. Sets are numbered below `width`.
+    sets: Vec<Option<usize>>,
+    rng: R,
+}
+
+impl<R: MazeRng> EllerRows<R> {
+    /// Creates an iterator over the rows of a `width` × `height` maze.
+    pub fn new(width: usize, height: usize, rng: R) -> Self {
+        Self { width, height, y: 0, sets: vec![None; width], rng }
+    }
+
+    /// Gives every cell that is not in a set a new set of its own, renumbering the sets
+    /// so they stay below `width`.
+    fn fill_sets(&mut self) {
+        let mut renumbered = vec![None; self.width];
+        let mut next = 0;
+        for set in self.sets.iter_mut() {
+            let new_set = match *set {
+                Some(old) => *renumbered[old].get_or_insert_with(|| {
+                    next += 1;
+                    next - 1
+                }),
+                None => {
+                    next += 1;
+                    next - 1
+                }
+            };
+            *set = Some(new_set);
+        }
+    }
+
+    /// Merges the set of the cell at `x + 1` into the set of the cell at `x`.
+    fn merge(&mut self, x: usize) {
+        let (keep, replace) = (self.sets[x], self.sets[x + 1]);
+        for set in self.sets.iter_mut().filter(|set| **set == replace) {
+            *set = keep;
+        }
+    }
+}
+
+impl<R: MazeRng> Iterator for EllerRows<R> {
+    type Item = EllerRow;
+
+    fn next(&mut self) -> Option<EllerRow> {
+        if self.y >= self.height || self.width == 0 {
+            return None;
+        }
+        let last = self.y == self.height - 1;
+        self.fill_sets();
+
+        let mut right_walls = vec![true; self.width - 1];
+        for (x, wall) in right_walls.iter_mut().enumerate() {
+            if self.sets[x] != self.sets[x + 1] && (last || self.rng.chance(0.5)) {
+                *wall = false;
+                self.merge(x);
+            }
+        }
+
+        let mut bottom_walls = Vec::new();
+        if !last {
+            let mut members = vec![Vec::new(); self.width];
+            for (x, set) in self.sets.iter().enumerate() {
+                members[set.unwrap()].push(x);
+            }
+            bottom_walls = vec![true; self.width];
+            for cells in members.iter().filter(|cells| !cells.is_empty()) {
+                let chosen = cells[self.rng.below(cells.len())];
+                for &x in cells {
+                    if x == chosen || self.rng.chance(0.5) {
+                        bottom_walls[x] = false;
+                    }
+                }
+            }
+            for (set, &wall) in self.sets.iter_mut().zip(&bottom_walls) {
+                if wall {
+                    *set = None;
+                }
+            }
+        }
+
+        let row = EllerRow { y: self.y, right_walls, bottom_walls };
+        self.y += 1;
+        Some(row)
+    }
+}
+
+/// Generates a perfect maze row by row using Eller's algorithm, as described for `EllerRows`.
+///
+/// Since the last row must join every set left over, it tends to have long horizontal corridors.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::eller;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let maze = eller(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+///
+/// assert_eq!(maze.passages().count(), 63);
+/// assert!(maze.solve().is_ok());
+/// ```
+pub fn eller<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let walls = EllerRows::new(WIDTH, HEIGHT, rng)
+        .flat_map(|row| row.walls().collect::<Vec<_>>())
+        .map(|wall| InteriorWall::from_wall(wall).unwrap())
+        .collect();
+    WallMaze::from_walls(start, end, walls)
+}
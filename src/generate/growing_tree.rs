@@ -0,0 +1,115 @@
+//! The growing-tree generator, whose texture depends on how it picks the next cell to grow from.
+
+use std::collections::VecDeque;
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, WallMaze};
+
+use super::{check_start_end, index, Carving};
+
+/// How `growing_tree` picks which of its active cells to grow the maze from next.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CellSelection {
+    /// The most recently added cell, which makes the generator a recursive backtracker with
+    /// long, winding corridors.
+    Newest,
+    /// The least recently added cell, which makes long straight corridors radiating out from
+    /// the start.
+    Oldest,
+    /// A random active cell, which behaves much like Prim's algorithm, with many short dead ends.
+    Random,
+    /// One of the above, picked afresh for every step with probability in proportion to its
+    /// weight. Weights that are all zero or negative are treated as `Newest`.
+    Weighted { newest: f64, oldest: f64, random: f64 },
+}
+
+impl CellSelection {
+    /// Returns the position in `active` of the cell to grow from next.
+    fn pick(self, len: usize, rng: &mut impl MazeRng) -> usize {
+        match self {
+            CellSelection::Newest => len - 1,
+            CellSelection::Oldest => 0,
+            CellSelection::Random => rng.below(len),
+            CellSelection::Weighted { newest, oldest, random } => {
+                let (newest, oldest, random) = (newest.max(0.0), oldest.max(0.0), random.max(0.0));
+                let total = newest + oldest + random;
+                if total <= 0.0 {
+                    return len - 1;
+                }
+                let choice = rng.next_f64() * total;
+                if choice < newest {
+                    len - 1
+                } else if choice < newest + oldest {
+                    0
+                } else {
+                    rng.below(len)
+                }
+            }
+        }
+    }
+}
+
+/// Generates a perfect maze with the growing-tree algorithm.
+///
+/// The generator keeps a list of active cells, starting with `start`. At each step it picks an
+/// active cell according to `selection` and knocks down the wall to a random unvisited neighbour,
+/// which becomes active in turn; once a cell has no unvisited neighbours left it stops being
+/// active. The choice of `selection` covers a range of textures, from the long corridors of
+/// the recursive backtracker to the short dead ends of Prim's algorithm.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::{growing_tree, CellSelection};
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let mut rng = Xoshiro256StarStar::new(1);
+///
+/// // Mostly winding corridors, with the occasional branch
+/// let selection = CellSelection::Weighted { newest: 3.0, oldest: 0.0, random: 1.0 };
+/// let maze = growing_tree(start, end, selection, &mut rng).unwrap();
+/// assert_eq!(maze.passages().count(), 63);
+/// ```
+pub fn growing_tree<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    selection: CellSelection,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    let mut visited = vec![false; WIDTH * HEIGHT];
+    let mut active = VecDeque::from([start]);
+    visited[index(start)] = true;
+
+    while !active.is_empty() {
+        let chosen = selection.pick(active.len(), rng);
+        let current = active[chosen];
+        let unvisited: Vec<_> = current.adjacent_positions()
+            .into_iter()
+            .filter(|&adj| !visited[index(adj)])
+            .collect();
+        if unvisited.is_empty() {
+            if selection == CellSelection::Random {
+                // The order of the active cells only matters to the other selections
+                active.swap_remove_back(chosen);
+            } else {
+                active.remove(chosen);
+            }
+        } else {
+            let next = unvisited[rng.below(unvisited.len())];
+            carving.carve(current, next);
+            visited[index(next)] = true;
+            active.push_back(next);
+        }
+    }
+    carving.into_maze(start, end)
+}
@@ -0,0 +1,74 @@
+//! The hunt-and-kill generator.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, WallMaze};
+
+use super::{cells, check_start_end, index, Carving};
+
+/// Generates a perfect maze with the hunt-and-kill algorithm.
+///
+/// Starting from `start`, the generator walks at random to unvisited neighbours, carving as it
+/// goes. When the walk reaches a cell with no unvisited neighbours, it hunts through the grid
+/// row by row for the first unvisited cell next to a visited one, joins the two, and walks on
+/// from there. The mazes look much like those of the recursive backtracker, with long winding
+/// corridors, but the hunt needs no stack of cells to return to.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::hunt_and_kill;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let maze = hunt_and_kill(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+/// assert_eq!(maze.passages().count(), 63);
+/// ```
+pub fn hunt_and_kill<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    let mut visited = vec![false; WIDTH * HEIGHT];
+    // Every cell before this one in row-major order has been visited
+    let mut first_unvisited = 0;
+    let mut current = Some(start);
+
+    while let Some(cell) = current {
+        visited[index(cell)] = true;
+        let unvisited: Vec<_> = cell.adjacent_positions()
+            .into_iter()
+            .filter(|&adj| !visited[index(adj)])
+            .collect();
+        if let Some(&next) = rng.choose(&unvisited) {
+            carving.carve(cell, next);
+            current = Some(next);
+            continue;
+        }
+
+        while first_unvisited < WIDTH * HEIGHT && visited[first_unvisited] {
+            first_unvisited += 1;
+        }
+        current = None;
+        for cell in cells::<WIDTH, HEIGHT>().skip(first_unvisited).filter(|&cell| !visited[index(cell)]) {
+            let visited_neighbours: Vec<_> = cell.adjacent_positions()
+                .into_iter()
+                .filter(|&adj| visited[index(adj)])
+                .collect();
+            if let Some(&neighbour) = rng.choose(&visited_neighbours) {
+                carving.carve(cell, neighbour);
+                current = Some(cell);
+                break;
+            }
+        }
+    }
+    carving.into_maze(start, end)
+}
@@ -0,0 +1,56 @@
+//! The randomized Kruskal generator.
+
+use crate::{MazeError, MazeWall};
+use crate::rng::MazeRng;
+use crate::union_find::UnionFind;
+use crate::wall_maze::{InteriorPosition, InteriorWall, WallMaze};
+
+use super::{check_start_end, index, Carving};
+
+/// Generates a perfect maze by a randomized version of Kruskal's minimum spanning tree algorithm.
+///
+/// Every interior wall is considered once, in a random order, and is removed if the cells on
+/// either side of it are not yet connected. The cells joined so far are tracked with a
+/// `UnionFind`. Since passages are added all over the grid at once rather than grown from a
+/// single cell, the resulting mazes have many short dead ends and few long corridors.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::kruskal;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let maze = kruskal(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+///
+/// assert_eq!(maze.passages().count(), 63);
+/// assert!(maze.solve().is_ok());
+/// ```
+pub fn kruskal<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut walls: Vec<_> = InteriorWall::all().collect();
+    rng.shuffle(&mut walls);
+
+    let mut carving = Carving::new();
+    let mut sets = UnionFind::new(WIDTH * HEIGHT);
+    for wall in walls {
+        let [cell1, cell2] = wall.surrounding_cells();
+        if sets.union(index(cell1), index(cell2)) {
+            carving.remove(wall);
+        }
+        if sets.set_count() == 1 {
+            break;
+        }
+    }
+    carving.into_maze(start, end)
+}
@@ -0,0 +1,222 @@
+//! Generation of random perfect mazes.
+//!
+//! A perfect maze has exactly one path between any two of its cells, so however the start
+//! and end are chosen the maze is solvable, and the generated maze satisfies the usual
+//! solvability guarantee of `WallMaze`.
+//!
+//! Generators begin with every interior wall present and carve passages through them until
+//! every cell of the grid is connected. They differ in the order in which passages are
+//! carved, which gives their mazes different textures:
+//! - `recursive_backtracker` makes long, winding corridors with few dead ends
+//! - `kruskal` makes many short dead ends and no long corridors
+//! - `prim` makes mazes radiating out from a seed cell, also with many short dead ends
+//! - `wilson` picks uniformly from every possible perfect maze, without any bias in texture
+//! - `eller` makes mazes one row at a time, and its rows can be streamed with `EllerRows`
+//!   using memory proportional to the width of the maze alone
+//! - `growing_tree` covers a range of textures, depending on its `CellSelection`
+//! - `hunt_and_kill` makes long, winding corridors like the recursive backtracker
+//! - `aldous_broder` also picks uniformly from every perfect maze, but more slowly than `wilson`
+//! - `binary_tree` and `sidewinder` work through the grid a cell at a time, and have strong
+//!   diagonal and vertical biases respectively
+//!
+//! `recursive_division` works the other way round, adding walls to an empty maze, and can
+//! leave open rooms rather than making a perfect maze.
+//!
+//! `Generator` names each of these, with its settings, so they can be chosen between at runtime.
+//!
+//! `targeted` searches for a maze from a generator whose `MazeMetrics`, such as the length of
+//! its solution and its number of dead ends, fall within a `DifficultyTarget`.
+//!
+//! Every generator draws its randomness from a `MazeRng`, so a maze generated from a seeded
+//! `Xoshiro256StarStar` can be reproduced exactly from the same seed.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, InteriorWall, Orientation, WallMaze};
+
+#[cfg(test)]
+mod tests;
+
+mod aldous_broder;
+mod backtracker;
+mod binary_tree;
+mod difficulty;
+mod division;
+mod eller;
+mod growing_tree;
+mod hunt_and_kill;
+mod kruskal;
+mod prim;
+mod sidewinder;
+mod wilson;
+pub use aldous_broder::aldous_broder;
+pub use backtracker::recursive_backtracker;
+pub use binary_tree::binary_tree;
+pub use difficulty::{targeted, DifficultyTarget, MazeMetrics, SearchBudget, TargetedMaze};
+pub use division::{recursive_division, DivisionOptions};
+pub use eller::{eller, EllerRow, EllerRows};
+pub use growing_tree::{growing_tree, CellSelection};
+pub use hunt_and_kill::hunt_and_kill;
+pub use kruskal::kruskal;
+pub use prim::prim;
+pub use sidewinder::sidewinder;
+pub use wilson::wilson;
+
+/// One of the maze generators of this module, together with its settings.
+///
+/// This allows a generator to be chosen at runtime, and different generators to be compared
+/// on the same grid.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::Generator;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+/// for generator in Generator::PRESETS {
+///     let maze = generator.generate(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+///     println!("{}: {} walls", generator.name(), maze.walls().count());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Generator {
+    RecursiveBacktracker,
+    Kruskal,
+    /// Prim's algorithm grown from the start of the maze.
+    Prim,
+    Wilson,
+    Eller,
+    RecursiveDivision(DivisionOptions),
+    GrowingTree(CellSelection),
+    HuntAndKill,
+    AldousBroder,
+    BinaryTree,
+    Sidewinder,
+}
+
+impl Generator {
+    /// Every generator, with default settings, and with the growing tree picking cells at random.
+    pub const PRESETS: [Generator; 11] = [
+        Generator::RecursiveBacktracker,
+        Generator::Kruskal,
+        Generator::Prim,
+        Generator::Wilson,
+        Generator::Eller,
+        Generator::RecursiveDivision(DivisionOptions { horizontal_bias: 0.5, min_chamber_size: 1 }),
+        Generator::GrowingTree(CellSelection::Random),
+        Generator::HuntAndKill,
+        Generator::AldousBroder,
+        Generator::BinaryTree,
+        Generator::Sidewinder,
+    ];
+
+    /// Returns the name of the algorithm, in lowercase words separated by hyphens.
+    pub fn name(self) -> &'static str {
+        match self {
+            Generator::RecursiveBacktracker => "recursive-backtracker",
+            Generator::Kruskal => "kruskal",
+            Generator::Prim => "prim",
+            Generator::Wilson => "wilson",
+            Generator::Eller => "eller",
+            Generator::RecursiveDivision(_) => "recursive-division",
+            Generator::GrowingTree(_) => "growing-tree",
+            Generator::HuntAndKill => "hunt-and-kill",
+            Generator::AldousBroder => "aldous-broder",
+            Generator::BinaryTree => "binary-tree",
+            Generator::Sidewinder => "sidewinder",
+        }
+    }
+
+    /// Generates a maze with this generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start and end positions are the same.
+    pub fn generate<const WIDTH: usize, const HEIGHT: usize>(
+        self,
+        start: InteriorPosition<WIDTH, HEIGHT>,
+        end: InteriorPosition<WIDTH, HEIGHT>,
+        rng: &mut impl MazeRng,
+    ) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+        match self {
+            Generator::RecursiveBacktracker => recursive_backtracker(start, end, rng),
+            Generator::Kruskal => kruskal(start, end, rng),
+            Generator::Prim => prim(start, end, None, rng),
+            Generator::Wilson => wilson(start, end, rng),
+            Generator::Eller => eller(start, end, rng),
+            Generator::RecursiveDivision(options) => recursive_division(start, end, options, rng),
+            Generator::GrowingTree(selection) => growing_tree(start, end, selection, rng),
+            Generator::HuntAndKill => hunt_and_kill(start, end, rng),
+            Generator::AldousBroder => aldous_broder(start, end, rng),
+            Generator::BinaryTree => binary_tree(start, end, rng),
+            Generator::Sidewinder => sidewinder(start, end, rng),
+        }
+    }
+}
+
+/// Returns an error if the start and end positions are the same, before any work is done.
+fn check_start_end<const WIDTH: usize, const HEIGHT: usize>(start: InteriorPosition<WIDTH, HEIGHT>, end: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+    if start == end {
+        Err(MazeError::StartIsEnd { x: start.get_x(), y: start.get_y() })
+    } else {
+        Ok(())
+    }
+}
+
+/// Iterates over every position of a WIDTH × HEIGHT grid in row-major order.
+fn cells<const WIDTH: usize, const HEIGHT: usize>() -> impl Iterator<Item = InteriorPosition<WIDTH, HEIGHT>> {
+    (0..HEIGHT).flat_map(|y| (0..WIDTH).map(move |x| InteriorPosition::new(x, y).unwrap()))
+}
+
+/// Returns the index of a position in row-major order.
+fn index<const WIDTH: usize, const HEIGHT: usize>(pos: InteriorPosition<WIDTH, HEIGHT>) -> usize {
+    pos.get_y() * WIDTH + pos.get_x()
+}
+
+/// The walls of a WIDTH × HEIGHT grid, which starts with every interior wall present and has
+/// passages carved through it.
+struct Carving<const WIDTH: usize, const HEIGHT: usize> {
+    present: Vec<bool>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Carving<WIDTH, HEIGHT> {
+    fn new() -> Self {
+        Self { present: vec![true; InteriorWall::<WIDTH, HEIGHT>::all().count()] }
+    }
+
+    /// Returns the position of a wall in the order of `InteriorWall::all`.
+    fn wall_index(wall: InteriorWall<WIDTH, HEIGHT>) -> usize {
+        let (x, y) = (wall.get_x(), wall.get_y());
+        match wall.get_orientation() {
+            Orientation::Horizontal => y * WIDTH + x,
+            Orientation::Vertical => (HEIGHT - 1) * WIDTH + y * (WIDTH - 1) + x,
+        }
+    }
+
+    /// Removes a wall.
+    fn remove(&mut self, wall: InteriorWall<WIDTH, HEIGHT>) {
+        self.present[Self::wall_index(wall)] = false;
+    }
+
+    /// Removes the wall between two adjacent positions.
+    fn carve(&mut self, pos1: InteriorPosition<WIDTH, HEIGHT>, pos2: InteriorPosition<WIDTH, HEIGHT>) {
+        self.remove(wall_between(pos1, pos2));
+    }
+
+    fn into_maze(self, start: InteriorPosition<WIDTH, HEIGHT>, end: InteriorPosition<WIDTH, HEIGHT>) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+        let walls = InteriorWall::all()
+            .zip(self.present)
+            .filter_map(|(wall, present)| present.then_some(wall))
+            .collect();
+        WallMaze::from_walls(start, end, walls)
+    }
+}
+
+/// Returns the wall between two adjacent positions.
+fn wall_between<const WIDTH: usize, const HEIGHT: usize>(pos1: InteriorPosition<WIDTH, HEIGHT>, pos2: InteriorPosition<WIDTH, HEIGHT>) -> InteriorWall<WIDTH, HEIGHT> {
+    let orientation = if pos1.get_y() == pos2.get_y() { Orientation::Vertical } else { Orientation::Horizontal };
+    InteriorWall::new(pos1.get_x().min(pos2.get_x()), pos1.get_y().min(pos2.get_y()), orientation).unwrap()
+}
@@ -0,0 +1,87 @@
+//! The randomized Prim generator.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, WallMaze};
+
+use super::{check_start_end, index, Carving};
+
+/// Where a cell stands as the maze grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CellState {
+    Outside,
+    Frontier,
+    InMaze,
+}
+
+/// Generates a perfect maze by a randomized version of Prim's minimum spanning tree algorithm.
+///
+/// The maze grows outwards from `seed_cell`, or from `start` if no seed cell is given. At each
+/// step a random cell is taken from the frontier of cells next to the maze and joined to a
+/// random neighbour already in the maze. Since any part of the frontier is as likely to grow as
+/// any other, the resulting mazes radiate out from the seed cell, with many short branches and
+/// few long corridors.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::prim;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let mut rng = Xoshiro256StarStar::new(1);
+///
+/// // Grow from the start
+/// let maze = prim(start, end, None, &mut rng).unwrap();
+/// assert_eq!(maze.passages().count(), 63);
+///
+/// // Grow from the middle of the grid
+/// let centre = InteriorPosition::new(4, 4).unwrap();
+/// let maze = prim(start, end, Some(centre), &mut rng).unwrap();
+/// assert!(maze.solve().is_ok());
+/// ```
+pub fn prim<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    seed_cell: Option<InteriorPosition<WIDTH, HEIGHT>>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    let mut state = vec![CellState::Outside; WIDTH * HEIGHT];
+    let mut frontier = Vec::new();
+
+    add_to_maze(seed_cell.unwrap_or(start), &mut state, &mut frontier);
+
+    while !frontier.is_empty() {
+        let cell = frontier.swap_remove(rng.below(frontier.len()));
+        let in_maze: Vec<_> = cell.adjacent_positions()
+            .into_iter()
+            .filter(|&adj| state[index(adj)] == CellState::InMaze)
+            .collect();
+        carving.carve(cell, in_maze[rng.below(in_maze.len())]);
+        add_to_maze(cell, &mut state, &mut frontier);
+    }
+    carving.into_maze(start, end)
+}
+
+/// Marks a cell as part of the maze and adds its neighbours outside the maze to the frontier.
+fn add_to_maze<const WIDTH: usize, const HEIGHT: usize>(
+    cell: InteriorPosition<WIDTH, HEIGHT>,
+    state: &mut [CellState],
+    frontier: &mut Vec<InteriorPosition<WIDTH, HEIGHT>>,
+) {
+    state[index(cell)] = CellState::InMaze;
+    for adj in cell.adjacent_positions() {
+        if state[index(adj)] == CellState::Outside {
+            state[index(adj)] = CellState::Frontier;
+            frontier.push(adj);
+        }
+    }
+}
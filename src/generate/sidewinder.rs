@@ -0,0 +1,60 @@
+//! The sidewinder generator.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{Direction, InteriorPosition, WallMaze};
+
+use super::{cells, check_start_end, Carving};
+
+/// Generates a perfect maze with the sidewinder algorithm.
+///
+/// The top row is a single corridor. Each lower row is worked through from left to right,
+/// building a run of cells joined horizontally: after each cell the run is, at random, either
+/// extended to the right or closed by knocking down the wall above a random cell of the run.
+/// The mazes have a vertical bias, and the path from any cell to the top row never moves down.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::sidewinder;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 0).unwrap();
+/// let maze = sidewinder(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+///
+/// // The top row is one long corridor
+/// assert_eq!(maze.solve().unwrap().len(), 8);
+/// ```
+pub fn sidewinder<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    let mut run = Vec::new();
+    for cell in cells::<WIDTH, HEIGHT>() {
+        run.push(cell);
+        let right = cell.shifted_by(Direction::Right).ok();
+        let close_run = match right {
+            None => true,
+            Some(_) => cell.get_y() > 0 && rng.chance(0.5),
+        };
+        if close_run {
+            let chosen = run[rng.below(run.len())];
+            if let Ok(above) = chosen.shifted_by(Direction::Up) {
+                carving.carve(chosen, above);
+            }
+            run.clear();
+        } else if let Some(right) = right {
+            carving.carve(cell, right);
+        }
+    }
+    carving.into_maze(start, end)
+}
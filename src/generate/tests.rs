@@ -0,0 +1,674 @@
+use super::*;
+use crate::rng::Xoshiro256StarStar;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Checks that every cell of the maze is reachable from every other by exactly one path,
+/// by checking that it is connected and has one passage fewer than it has cells.
+fn assert_perfect<const WIDTH: usize, const HEIGHT: usize>(maze: &WallMaze<WIDTH, HEIGHT>) {
+    assert_eq!(maze.passages().count(), WIDTH * HEIGHT - 1);
+
+    let mut seen = HashSet::from([maze.get_start()]);
+    let mut queue = VecDeque::from([maze.get_start()]);
+    while let Some(pos) = queue.pop_front() {
+        for adj in maze.open_neighbours(pos) {
+            if seen.insert(adj) {
+                queue.push_back(adj);
+            }
+        }
+    }
+    assert_eq!(seen.len(), WIDTH * HEIGHT);
+}
+
+#[test]
+fn test_recursive_backtracker_is_perfect() {
+    let mut rng = Xoshiro256StarStar::new(11);
+    for _ in 0..20 {
+        let start = InteriorPosition::<9, 6>::new(0, 0).unwrap();
+        let end = InteriorPosition::<9, 6>::new(8, 5).unwrap();
+        let maze = recursive_backtracker(start, end, &mut rng).unwrap();
+        assert_perfect(&maze);
+        assert_eq!((maze.get_start(), maze.get_end()), (start, end));
+    }
+}
+
+#[test]
+fn test_recursive_backtracker_any_start_and_end() {
+    let mut rng = Xoshiro256StarStar::new(12);
+    for (x1, y1, x2, y2) in [(2, 2, 0, 4), (4, 0, 0, 4), (3, 1, 3, 2)] {
+        let start = InteriorPosition::<5, 5>::new(x1, y1).unwrap();
+        let end = InteriorPosition::<5, 5>::new(x2, y2).unwrap();
+        let maze = recursive_backtracker(start, end, &mut rng).unwrap();
+        assert_perfect(&maze);
+        let path = maze.solve().unwrap();
+        assert_eq!((path.first(), path.last()), (Some(&start), Some(&end)));
+    }
+
+    let pos = InteriorPosition::<5, 5>::new(1, 1).unwrap();
+    assert_eq!(recursive_backtracker(pos, pos, &mut rng), Err(MazeError::StartIsEnd { x: 1, y: 1 }));
+}
+
+#[test]
+fn test_recursive_backtracker_thin_grids() {
+    let mut rng = Xoshiro256StarStar::new(13);
+    let maze = recursive_backtracker(InteriorPosition::<1, 7>::new(0, 0).unwrap(), InteriorPosition::new(0, 6).unwrap(), &mut rng).unwrap();
+    assert_perfect(&maze);
+    assert_eq!(maze.walls().count(), 0);
+
+    let maze = recursive_backtracker(InteriorPosition::<7, 1>::new(6, 0).unwrap(), InteriorPosition::new(0, 0).unwrap(), &mut rng).unwrap();
+    assert_perfect(&maze);
+    assert_eq!(maze.solve().unwrap().len(), 7);
+}
+
+#[test]
+fn test_recursive_backtracker_golden_output() {
+    // Locks the maze generated from a fixed seed, so that seeded mazes stay reproducible
+    let start = InteriorPosition::<5, 5>::new(0, 0).unwrap();
+    let end = InteriorPosition::<5, 5>::new(4, 4).unwrap();
+    let maze = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(2024)).unwrap();
+
+    let walls: Vec<_> = maze.walls().map(|w| (w.get_x(), w.get_y(), w.get_orientation())).collect();
+    assert_eq!(walls, vec![
+        (0, 0, Orientation::Horizontal), (2, 0, Orientation::Horizontal), (3, 0, Orientation::Horizontal),
+        (1, 1, Orientation::Horizontal), (2, 2, Orientation::Horizontal), (0, 3, Orientation::Horizontal),
+        (1, 3, Orientation::Horizontal), (1, 0, Orientation::Vertical), (1, 1, Orientation::Vertical),
+        (3, 1, Orientation::Vertical), (0, 2, Orientation::Vertical), (2, 2, Orientation::Vertical),
+        (3, 2, Orientation::Vertical), (1, 3, Orientation::Vertical), (2, 3, Orientation::Vertical),
+        (3, 3, Orientation::Vertical),
+    ]);
+}
+
+#[test]
+fn test_recursive_backtracker_same_seed_same_maze() {
+    let start = InteriorPosition::<12, 9>::new(0, 4).unwrap();
+    let end = InteriorPosition::<12, 9>::new(11, 4).unwrap();
+    let maze1 = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(99)).unwrap();
+    let maze2 = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(99)).unwrap();
+    let maze3 = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(100)).unwrap();
+
+    assert_eq!(maze1, maze2);
+    assert_ne!(maze1, maze3);
+}
+
+/// Counts the cells with exactly one open neighbour.
+fn dead_ends<const WIDTH: usize, const HEIGHT: usize>(maze: &WallMaze<WIDTH, HEIGHT>) -> usize {
+    cells::<WIDTH, HEIGHT>()
+        .filter(|&pos| maze.open_neighbours(pos).len() == 1)
+        .count()
+}
+
+#[test]
+fn test_kruskal_is_perfect() {
+    let mut rng = Xoshiro256StarStar::new(21);
+    for _ in 0..20 {
+        let start = InteriorPosition::<7, 10>::new(3, 0).unwrap();
+        let end = InteriorPosition::<7, 10>::new(3, 9).unwrap();
+        let maze = kruskal(start, end, &mut rng).unwrap();
+        assert_perfect(&maze);
+    }
+
+    let maze = kruskal(InteriorPosition::<1, 5>::new(0, 0).unwrap(), InteriorPosition::new(0, 4).unwrap(), &mut rng).unwrap();
+    assert_perfect(&maze);
+
+    let pos = InteriorPosition::<3, 3>::new(2, 0).unwrap();
+    assert_eq!(kruskal(pos, pos, &mut rng), Err(MazeError::StartIsEnd { x: 2, y: 0 }));
+}
+
+#[test]
+fn test_kruskal_golden_output() {
+    let start = InteriorPosition::<4, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<4, 4>::new(3, 3).unwrap();
+    let maze = kruskal(start, end, &mut Xoshiro256StarStar::new(2024)).unwrap();
+
+    let walls: Vec<_> = maze.walls().map(|w| (w.get_x(), w.get_y(), w.get_orientation())).collect();
+    assert_eq!(walls, vec![
+        (1, 0, Orientation::Horizontal), (3, 0, Orientation::Horizontal), (0, 1, Orientation::Horizontal),
+        (2, 2, Orientation::Horizontal), (0, 1, Orientation::Vertical), (1, 1, Orientation::Vertical),
+        (2, 1, Orientation::Vertical), (0, 2, Orientation::Vertical), (2, 3, Orientation::Vertical),
+    ]);
+}
+
+#[test]
+fn test_kruskal_has_more_dead_ends_than_backtracker() {
+    let start = InteriorPosition::<20, 20>::new(0, 0).unwrap();
+    let end = InteriorPosition::<20, 20>::new(19, 19).unwrap();
+    let mut rng = Xoshiro256StarStar::new(22);
+    let (mut kruskal_dead_ends, mut backtracker_dead_ends) = (0, 0);
+    for _ in 0..5 {
+        kruskal_dead_ends += dead_ends(&kruskal(start, end, &mut rng).unwrap());
+        backtracker_dead_ends += dead_ends(&recursive_backtracker(start, end, &mut rng).unwrap());
+    }
+    assert!(kruskal_dead_ends > 2 * backtracker_dead_ends, "{} vs {}", kruskal_dead_ends, backtracker_dead_ends);
+}
+
+#[test]
+fn test_prim_is_perfect() {
+    let mut rng = Xoshiro256StarStar::new(31);
+    let start = InteriorPosition::<10, 6>::new(0, 5).unwrap();
+    let end = InteriorPosition::<10, 6>::new(9, 0).unwrap();
+    for seed_cell in [None, Some(InteriorPosition::new(5, 3).unwrap()), Some(end)] {
+        for _ in 0..10 {
+            let maze = prim(start, end, seed_cell, &mut rng).unwrap();
+            assert_perfect(&maze);
+            assert_eq!((maze.get_start(), maze.get_end()), (start, end));
+        }
+    }
+
+    let pos = InteriorPosition::<3, 3>::new(0, 2).unwrap();
+    assert_eq!(prim(pos, pos, None, &mut rng), Err(MazeError::StartIsEnd { x: 0, y: 2 }));
+}
+
+#[test]
+fn test_prim_seed_cell_defaults_to_start() {
+    let start = InteriorPosition::<9, 9>::new(2, 7).unwrap();
+    let end = InteriorPosition::<9, 9>::new(8, 0).unwrap();
+    let default = prim(start, end, None, &mut Xoshiro256StarStar::new(32)).unwrap();
+    let explicit = prim(start, end, Some(start), &mut Xoshiro256StarStar::new(32)).unwrap();
+    let elsewhere = prim(start, end, Some(end), &mut Xoshiro256StarStar::new(32)).unwrap();
+
+    assert_eq!(default, explicit);
+    assert_ne!(default, elsewhere);
+}
+
+#[test]
+fn test_prim_golden_output() {
+    let start = InteriorPosition::<4, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<4, 4>::new(3, 3).unwrap();
+    let maze = prim(start, end, None, &mut Xoshiro256StarStar::new(2024)).unwrap();
+
+    let walls: Vec<_> = maze.walls().map(|w| (w.get_x(), w.get_y(), w.get_orientation())).collect();
+    assert_eq!(walls, vec![
+        (2, 0, Orientation::Horizontal), (1, 1, Orientation::Horizontal), (2, 1, Orientation::Horizontal),
+        (3, 1, Orientation::Horizontal), (0, 2, Orientation::Horizontal), (1, 2, Orientation::Horizontal),
+        (0, 1, Orientation::Vertical), (2, 1, Orientation::Vertical), (2, 3, Orientation::Vertical),
+    ]);
+}
+
+#[test]
+fn test_prim_has_more_dead_ends_than_backtracker() {
+    let start = InteriorPosition::<20, 20>::new(0, 0).unwrap();
+    let end = InteriorPosition::<20, 20>::new(19, 19).unwrap();
+    let mut rng = Xoshiro256StarStar::new(33);
+    let (mut prim_dead_ends, mut backtracker_dead_ends) = (0, 0);
+    for _ in 0..5 {
+        prim_dead_ends += dead_ends(&prim(start, end, None, &mut rng).unwrap());
+        backtracker_dead_ends += dead_ends(&recursive_backtracker(start, end, &mut rng).unwrap());
+    }
+    assert!(prim_dead_ends > 2 * backtracker_dead_ends, "{} vs {}", prim_dead_ends, backtracker_dead_ends);
+}
+
+#[test]
+fn test_wilson_is_perfect() {
+    let mut rng = Xoshiro256StarStar::new(41);
+    for _ in 0..10 {
+        let start = InteriorPosition::<8, 5>::new(7, 4).unwrap();
+        let end = InteriorPosition::<8, 5>::new(0, 0).unwrap();
+        let maze = wilson(start, end, &mut rng).unwrap();
+        assert_perfect(&maze);
+    }
+
+    let pos = InteriorPosition::<3, 3>::new(1, 1).unwrap();
+    assert_eq!(wilson(pos, pos, &mut rng), Err(MazeError::StartIsEnd { x: 1, y: 1 }));
+}
+
+#[test]
+fn test_wilson_golden_output() {
+    let start = InteriorPosition::<4, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<4, 4>::new(3, 3).unwrap();
+    let maze = wilson(start, end, &mut Xoshiro256StarStar::new(2024)).unwrap();
+
+    let walls: Vec<_> = maze.walls().map(|w| (w.get_x(), w.get_y(), w.get_orientation())).collect();
+    assert_eq!(walls, vec![
+        (0, 0, Orientation::Horizontal), (1, 0, Orientation::Horizontal), (2, 0, Orientation::Horizontal),
+        (1, 1, Orientation::Horizontal), (3, 1, Orientation::Horizontal), (0, 2, Orientation::Horizontal),
+        (1, 2, Orientation::Horizontal), (1, 2, Orientation::Vertical), (2, 2, Orientation::Vertical),
+    ]);
+}
+
+/// Lists the walls of every perfect 3 × 3 maze, by checking which choices of the 8 passages
+/// out of the 12 possible ones connect all 9 cells.
+fn all_perfect_3x3_mazes() -> HashSet<Vec<InteriorWall<3, 3>>> {
+    let walls: Vec<_> = InteriorWall::<3, 3>::all().collect();
+    let mut mazes = HashSet::new();
+    for passages in 0u32..1 << walls.len() {
+        if passages.count_ones() != 8 {
+            continue;
+        }
+        let mut sets = crate::union_find::UnionFind::new(9);
+        for (i, wall) in walls.iter().enumerate() {
+            if passages & (1 << i) != 0 {
+                let [cell1, cell2] = crate::MazeWall::surrounding_cells(wall);
+                sets.union(index(cell1), index(cell2));
+            }
+        }
+        if sets.set_count() == 1 {
+            mazes.insert((0..walls.len()).filter(|i| passages & (1 << i) == 0).map(|i| walls[i]).collect());
+        }
+    }
+    mazes
+}
+
+#[test]
+fn test_wilson_is_uniform_on_3x3() {
+    let all_mazes = all_perfect_3x3_mazes();
+    assert_eq!(all_mazes.len(), 192);
+
+    const SAMPLES_PER_MAZE: usize = 100;
+    let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    let mut rng = Xoshiro256StarStar::new(42);
+    let mut counts: HashMap<Vec<InteriorWall<3, 3>>, usize> = HashMap::new();
+    for _ in 0..192 * SAMPLES_PER_MAZE {
+        let maze = wilson(start, end, &mut rng).unwrap();
+        *counts.entry(maze.walls().collect()).or_default() += 1;
+    }
+    assert!(counts.keys().all(|walls| all_mazes.contains(walls)));
+    assert_eq!(counts.len(), 192);
+
+    // Pearson's chi-squared statistic has 191 degrees of freedom, so a mean of 191 and a standard
+    // deviation of about 19.5. The recursive backtracker would not even get this far, since there
+    // are mazes it never produces from a fixed start.
+    let expected = SAMPLES_PER_MAZE as f64;
+    let chi_squared: f64 = counts.values().map(|&count| (count as f64 - expected).powi(2) / expected).sum();
+    assert!(chi_squared < 191.0 + 5.0 * 19.5, "chi-squared statistic {}", chi_squared);
+}
+
+#[test]
+fn test_eller_is_perfect() {
+    let mut rng = Xoshiro256StarStar::new(51);
+    for _ in 0..20 {
+        let start = InteriorPosition::<9, 7>::new(0, 0).unwrap();
+        let end = InteriorPosition::<9, 7>::new(8, 6).unwrap();
+        let maze = eller(start, end, &mut rng).unwrap();
+        assert_perfect(&maze);
+    }
+
+    assert_perfect(&eller(InteriorPosition::<1, 6>::new(0, 0).unwrap(), InteriorPosition::new(0, 5).unwrap(), &mut rng).unwrap());
+    assert_perfect(&eller(InteriorPosition::<6, 1>::new(0, 0).unwrap(), InteriorPosition::new(5, 0).unwrap(), &mut rng).unwrap());
+
+    let pos = InteriorPosition::<3, 3>::new(2, 1).unwrap();
+    assert_eq!(eller(pos, pos, &mut rng), Err(MazeError::StartIsEnd { x: 2, y: 1 }));
+}
+
+#[test]
+fn test_eller_rows_match_collected_maze() {
+    let start = InteriorPosition::<6, 8>::new(0, 0).unwrap();
+    let end = InteriorPosition::<6, 8>::new(5, 7).unwrap();
+    let maze = eller(start, end, &mut Xoshiro256StarStar::new(52)).unwrap();
+    let rows: Vec<_> = EllerRows::new(6, 8, Xoshiro256StarStar::new(52)).collect();
+
+    assert_eq!(rows.len(), 8);
+    for (y, row) in rows.iter().enumerate() {
+        assert_eq!(row.get_y(), y);
+        for x in 0..6 {
+            let right = InteriorWall::new(x, y, Orientation::Vertical).is_ok_and(|wall| maze.has_wall(wall));
+            let bottom = InteriorWall::new(x, y, Orientation::Horizontal).is_ok_and(|wall| maze.has_wall(wall));
+            assert_eq!(row.has_right_wall(x), right);
+            assert_eq!(row.has_bottom_wall(x), bottom);
+        }
+    }
+    assert!((0..6).all(|x| !rows[7].has_bottom_wall(x)));
+}
+
+#[test]
+fn test_eller_rows_stream_tall_maze() {
+    // Checks the maze is perfect a row at a time, tracking only which cells of the current row
+    // are connected through the rows above
+    const WIDTH: usize = 12;
+    let mut rng = Xoshiro256StarStar::new(53);
+    let mut passages = 0;
+    let mut sets = crate::union_find::UnionFind::new(2 * WIDTH);
+    let mut rows = 0;
+    for row in EllerRows::new(WIDTH, 20_000, &mut rng) {
+        // Cells of this row are 0..WIDTH, cells of the row below are WIDTH..2 * WIDTH
+        for x in 0..WIDTH - 1 {
+            if !row.has_right_wall(x) {
+                assert!(sets.union(x, x + 1), "row {} has a loop", row.get_y());
+                passages += 1;
+            }
+        }
+        let mut next = crate::union_find::UnionFind::new(2 * WIDTH);
+        for x in 0..WIDTH {
+            if row.get_y() < 19_999 && !row.has_bottom_wall(x) {
+                assert!(sets.union(x, WIDTH + x), "row {} has a loop", row.get_y());
+                passages += 1;
+            }
+        }
+        // Every set of this row must continue into the row below, or the maze would be disconnected
+        if row.get_y() < 19_999 {
+            for x in 0..WIDTH {
+                assert!((0..WIDTH).any(|below| sets.connected(x, WIDTH + below)));
+            }
+            for a in 0..WIDTH {
+                for b in 0..WIDTH {
+                    if sets.connected(WIDTH + a, WIDTH + b) {
+                        next.union(a, b);
+                    }
+                }
+            }
+        } else {
+            assert_eq!((0..WIDTH).filter(|&x| sets.find(x) == x).count(), 1);
+        }
+        sets = next;
+        rows += 1;
+    }
+    assert_eq!(rows, 20_000);
+    assert_eq!(passages, WIDTH * 20_000 - 1);
+}
+
+#[test]
+fn test_eller_golden_output() {
+    let start = InteriorPosition::<4, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<4, 4>::new(3, 3).unwrap();
+    let maze = eller(start, end, &mut Xoshiro256StarStar::new(2024)).unwrap();
+
+    let walls: Vec<_> = maze.walls().map(|w| (w.get_x(), w.get_y(), w.get_orientation())).collect();
+    assert_eq!(walls, vec![
+        (1, 0, Orientation::Horizontal), (1, 1, Orientation::Horizontal), (2, 1, Orientation::Horizontal),
+        (1, 2, Orientation::Horizontal), (1, 0, Orientation::Vertical), (1, 1, Orientation::Vertical),
+        (2, 1, Orientation::Vertical), (1, 2, Orientation::Vertical), (2, 3, Orientation::Vertical),
+    ]);
+}
+
+#[test]
+fn test_recursive_division_is_perfect() {
+    let mut rng = Xoshiro256StarStar::new(61);
+    for _ in 0..20 {
+        let start = InteriorPosition::<11, 7>::new(0, 3).unwrap();
+        let end = InteriorPosition::<11, 7>::new(10, 3).unwrap();
+        let maze = recursive_division(start, end, DivisionOptions::default(), &mut rng).unwrap();
+        assert_perfect(&maze);
+    }
+
+    let pos = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    assert_eq!(recursive_division(pos, pos, DivisionOptions::default(), &mut rng), Err(MazeError::StartIsEnd { x: 0, y: 0 }));
+}
+
+#[test]
+fn test_recursive_division_orientation_bias() {
+    let start = InteriorPosition::<9, 9>::new(0, 0).unwrap();
+    let end = InteriorPosition::<9, 9>::new(8, 8).unwrap();
+    let mut rng = Xoshiro256StarStar::new(62);
+
+    // Always dividing horizontally leaves every row as one long corridor
+    let horizontal = DivisionOptions { horizontal_bias: 1.0, ..DivisionOptions::default() };
+    let maze = recursive_division(start, end, horizontal, &mut rng).unwrap();
+    assert_perfect(&maze);
+    assert!(maze.walls().all(|wall| wall.get_orientation() == Orientation::Horizontal));
+    assert_eq!(maze.walls().count(), 8 * 8);
+
+    let vertical = DivisionOptions { horizontal_bias: 0.0, ..DivisionOptions::default() };
+    let maze = recursive_division(start, end, vertical, &mut rng).unwrap();
+    assert_perfect(&maze);
+    assert!(maze.walls().all(|wall| wall.get_orientation() == Orientation::Vertical));
+
+    let mostly_horizontal = DivisionOptions { horizontal_bias: 0.9, ..DivisionOptions::default() };
+    let maze = recursive_division(start, end, mostly_horizontal, &mut rng).unwrap();
+    let horizontal_walls = maze.walls().filter(|wall| wall.get_orientation() == Orientation::Horizontal).count();
+    assert!(horizontal_walls > 2 * (maze.walls().count() - horizontal_walls));
+}
+
+#[test]
+fn test_recursive_division_min_chamber_size() {
+    const SIZE: usize = 14;
+    let start = InteriorPosition::<SIZE, SIZE>::new(0, 0).unwrap();
+    let end = InteriorPosition::<SIZE, SIZE>::new(SIZE - 1, SIZE - 1).unwrap();
+    let mut rng = Xoshiro256StarStar::new(63);
+    for min_chamber_size in [2, 3, 5] {
+        let options = DivisionOptions { min_chamber_size, ..DivisionOptions::default() };
+        let maze = recursive_division(start, end, options, &mut rng).unwrap();
+        assert!(maze.passages().count() > SIZE * SIZE - 1);
+        assert!(maze.solve().is_ok());
+
+        // Parallel walls in the same row or column are never closer than the minimum chamber size
+        for along in 0..SIZE {
+            for orientation in [Orientation::Horizontal, Orientation::Vertical] {
+                let mut previous = -1;
+                for across in 0..SIZE - 1 {
+                    let wall = match orientation {
+                        Orientation::Horizontal => InteriorWall::new(along, across, orientation),
+                        Orientation::Vertical => InteriorWall::new(across, along, orientation),
+                    };
+                    if maze.has_wall(wall.unwrap()) {
+                        assert!(across as isize - previous >= min_chamber_size as isize);
+                        previous = across as isize;
+                    }
+                }
+                assert!(SIZE as isize - 1 - previous >= min_chamber_size as isize);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_recursive_division_golden_output() {
+    let start = InteriorPosition::<4, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<4, 4>::new(3, 3).unwrap();
+    let maze = recursive_division(start, end, DivisionOptions::default(), &mut Xoshiro256StarStar::new(2024)).unwrap();
+
+    let walls: Vec<_> = maze.walls().map(|w| (w.get_x(), w.get_y(), w.get_orientation())).collect();
+    assert_eq!(walls, vec![
+        (3, 0, Orientation::Horizontal), (0, 1, Orientation::Horizontal), (1, 1, Orientation::Horizontal),
+        (2, 1, Orientation::Horizontal), (1, 2, Orientation::Horizontal), (2, 2, Orientation::Horizontal),
+        (3, 2, Orientation::Horizontal), (0, 0, Orientation::Vertical), (1, 0, Orientation::Vertical),
+    ]);
+}
+
+#[test]
+fn test_presets_are_perfect() {
+    let mut rng = Xoshiro256StarStar::new(71);
+    let start = InteriorPosition::<9, 8>::new(4, 4).unwrap();
+    let end = InteriorPosition::<9, 8>::new(0, 7).unwrap();
+    for generator in Generator::PRESETS {
+        for _ in 0..5 {
+            let maze = generator.generate(start, end, &mut rng).unwrap();
+            assert_perfect(&maze);
+            assert_eq!((maze.get_start(), maze.get_end()), (start, end), "{}", generator.name());
+        }
+        assert_eq!(generator.generate(start, start, &mut rng), Err(MazeError::StartIsEnd { x: 4, y: 4 }));
+    }
+
+    let names: HashSet<_> = Generator::PRESETS.iter().map(|generator| generator.name()).collect();
+    assert_eq!(names.len(), Generator::PRESETS.len());
+}
+
+#[test]
+fn test_growing_tree_selections_are_perfect() {
+    let mut rng = Xoshiro256StarStar::new(72);
+    let start = InteriorPosition::<10, 7>::new(0, 0).unwrap();
+    let end = InteriorPosition::<10, 7>::new(9, 6).unwrap();
+    for selection in [
+        CellSelection::Newest,
+        CellSelection::Oldest,
+        CellSelection::Random,
+        CellSelection::Weighted { newest: 1.0, oldest: 1.0, random: 1.0 },
+        CellSelection::Weighted { newest: 0.0, oldest: 0.0, random: 0.0 },
+    ] {
+        for _ in 0..5 {
+            assert_perfect(&growing_tree(start, end, selection, &mut rng).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_growing_tree_newest_is_recursive_backtracker() {
+    let start = InteriorPosition::<12, 12>::new(3, 5).unwrap();
+    let end = InteriorPosition::<12, 12>::new(11, 0).unwrap();
+    for seed in 0..5 {
+        let growing = growing_tree(start, end, CellSelection::Newest, &mut Xoshiro256StarStar::new(seed)).unwrap();
+        let backtracker = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(seed)).unwrap();
+        assert_eq!(growing, backtracker);
+    }
+}
+
+#[test]
+fn test_growing_tree_selection_changes_texture() {
+    let start = InteriorPosition::<20, 20>::new(0, 0).unwrap();
+    let end = InteriorPosition::<20, 20>::new(19, 19).unwrap();
+    let mut rng = Xoshiro256StarStar::new(73);
+    let (mut newest_dead_ends, mut random_dead_ends) = (0, 0);
+    for _ in 0..5 {
+        newest_dead_ends += dead_ends(&growing_tree(start, end, CellSelection::Newest, &mut rng).unwrap());
+        random_dead_ends += dead_ends(&growing_tree(start, end, CellSelection::Random, &mut rng).unwrap());
+    }
+    assert!(random_dead_ends > 2 * newest_dead_ends, "{} vs {}", random_dead_ends, newest_dead_ends);
+}
+
+#[test]
+fn test_binary_tree_texture() {
+    let start = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+    let end = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+    let mut rng = Xoshiro256StarStar::new(74);
+    for _ in 0..5 {
+        let maze = binary_tree(start, end, &mut rng).unwrap();
+        for i in 0..9 {
+            assert!(!maze.has_wall(InteriorWall::new(i, 0, Orientation::Vertical).unwrap()));
+            assert!(!maze.has_wall(InteriorWall::new(0, i, Orientation::Horizontal).unwrap()));
+        }
+        // Every cell can only be left upwards or leftwards towards the corner
+        assert_eq!(maze.solve().unwrap().len(), 19);
+    }
+}
+
+#[test]
+fn test_sidewinder_texture() {
+    let start = InteriorPosition::<10, 10>::new(3, 9).unwrap();
+    let end = InteriorPosition::<10, 10>::new(6, 0).unwrap();
+    let mut rng = Xoshiro256StarStar::new(75);
+    for _ in 0..5 {
+        let maze = sidewinder(start, end, &mut rng).unwrap();
+        for x in 0..9 {
+            assert!(!maze.has_wall(InteriorWall::new(x, 0, Orientation::Vertical).unwrap()));
+        }
+        // Each run of a lower row has exactly one way up
+        let path = maze.solve().unwrap();
+        assert!(path.windows(2).all(|step| step[1].get_y() <= step[0].get_y()));
+    }
+}
+
+#[test]
+fn test_aldous_broder_is_uniform_on_3x3() {
+    let all_mazes = all_perfect_3x3_mazes();
+    const SAMPLES_PER_MAZE: usize = 100;
+    let start = InteriorPosition::<3, 3>::new(1, 1).unwrap();
+    let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    let mut rng = Xoshiro256StarStar::new(76);
+    let mut counts: HashMap<Vec<InteriorWall<3, 3>>, usize> = HashMap::new();
+    for _ in 0..192 * SAMPLES_PER_MAZE {
+        let maze = aldous_broder(start, end, &mut rng).unwrap();
+        *counts.entry(maze.walls().collect()).or_default() += 1;
+    }
+    assert!(counts.keys().all(|walls| all_mazes.contains(walls)));
+    assert_eq!(counts.len(), 192);
+
+    let expected = SAMPLES_PER_MAZE as f64;
+    let chi_squared: f64 = counts.values().map(|&count| (count as f64 - expected).powi(2) / expected).sum();
+    assert!(chi_squared < 191.0 + 5.0 * 19.5, "chi-squared statistic {}", chi_squared);
+}
+
+#[test]
+fn test_presets_golden_output() {
+    let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    let generators = [
+        Generator::GrowingTree(CellSelection::Weighted { newest: 1.0, oldest: 1.0, random: 1.0 }),
+        Generator::HuntAndKill,
+        Generator::AldousBroder,
+        Generator::BinaryTree,
+        Generator::Sidewinder,
+    ];
+    let expected = [
+        [(1, 0, Orientation::Horizontal), (1, 1, Orientation::Horizontal), (1, 1, Orientation::Vertical), (1, 2, Orientation::Vertical)],
+        [(0, 0, Orientation::Horizontal), (1, 1, Orientation::Horizontal), (1, 0, Orientation::Vertical), (1, 1, Orientation::Vertical)],
+        [(0, 0, Orientation::Horizontal), (2, 0, Orientation::Horizontal), (0, 1, Orientation::Horizontal), (1, 1, Orientation::Vertical)],
+        [(2, 0, Orientation::Horizontal), (0, 1, Orientation::Vertical), (0, 2, Orientation::Vertical), (1, 2, Orientation::Vertical)],
+        [(1, 0, Orientation::Horizontal), (1, 1, Orientation::Vertical), (0, 2, Orientation::Vertical), (1, 2, Orientation::Vertical)],
+    ];
+    for (generator, expected) in generators.into_iter().zip(expected) {
+        let maze = generator.generate(start, end, &mut Xoshiro256StarStar::new(2024)).unwrap();
+        let walls: Vec<_> = maze.walls().map(|w| (w.get_x(), w.get_y(), w.get_orientation())).collect();
+        assert_eq!(walls, expected, "{}", generator.name());
+    }
+}
+
+#[test]
+fn test_metrics_match_maze() {
+    let mut rng = Xoshiro256StarStar::new(20);
+    let start = InteriorPosition::<12, 10>::new(0, 0).unwrap();
+    let end = InteriorPosition::<12, 10>::new(11, 9).unwrap();
+    for generator in Generator::PRESETS {
+        let maze = generator.generate(start, end, &mut rng).unwrap();
+        let metrics = MazeMetrics::measure(&maze);
+        let path = crate::Maze::solve_shortest(&maze).unwrap();
+
+        assert_eq!(metrics.solution_length, path.len() - 1);
+        assert_eq!(metrics.dead_ends, dead_ends(&maze));
+        assert!(metrics.turns < metrics.solution_length);
+        assert!(metrics.branching_factor >= 1.0);
+    }
+}
+
+#[test]
+fn test_metrics_of_corridor() {
+    // An empty 5 × 1 maze is a single corridor from one end to the other
+    let start = InteriorPosition::<5, 1>::new(0, 0).unwrap();
+    let end = InteriorPosition::<5, 1>::new(4, 0).unwrap();
+    let maze = WallMaze::new(start, end).unwrap();
+    assert_eq!(
+        MazeMetrics::measure(&maze),
+        MazeMetrics { solution_length: 4, dead_ends: 2, branching_factor: 1.0, turns: 0 }
+    );
+
+    // Starting in the middle, the first step has a choice of two ways
+    let mut maze = maze;
+    maze.move_start(InteriorPosition::new(2, 0).unwrap()).unwrap();
+    assert_eq!(MazeMetrics::measure(&maze).branching_factor, 1.5);
+}
+
+#[test]
+fn test_targeted_accepts_first_maze_meeting_target() {
+    let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+    let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+    let found = targeted(Generator::Kruskal, start, end, &DifficultyTarget::default(), SearchBudget::default(), &mut Xoshiro256StarStar::new(21)).unwrap();
+
+    assert!(found.met);
+    assert_eq!((found.generations, found.mutations), (1, 0));
+    assert_eq!(found.maze, kruskal(start, end, &mut Xoshiro256StarStar::new(21)).unwrap());
+    assert_eq!(found.metrics, MazeMetrics::measure(&found.maze));
+}
+
+#[test]
+fn test_targeted_mutates_towards_target() {
+    let start = InteriorPosition::<15, 15>::new(0, 0).unwrap();
+    let end = InteriorPosition::<15, 15>::new(14, 14).unwrap();
+    let target = DifficultyTarget { solution_length: 100..=usize::MAX, ..Default::default() };
+    let budget = SearchBudget { generations: 1, mutations: 2000 };
+    let found = targeted(Generator::Kruskal, start, end, &target, budget, &mut Xoshiro256StarStar::new(22)).unwrap();
+
+    let generated = MazeMetrics::measure(&kruskal(start, end, &mut Xoshiro256StarStar::new(22)).unwrap());
+    assert!(generated.solution_length < 100);
+    assert!(found.met);
+    assert!(found.metrics.solution_length >= 100);
+    assert!(found.mutations > 0 && found.mutations <= 2000);
+    assert_perfect(&found.maze);
+}
+
+#[test]
+fn test_targeted_reports_closest_when_budget_runs_out() {
+    let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+    let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+    // No 8 × 8 maze has a solution this long
+    let target = DifficultyTarget { solution_length: 64..=usize::MAX, turns: 0..=10, ..Default::default() };
+    let budget = SearchBudget { generations: 5, mutations: 50 };
+    let found = targeted(Generator::Wilson, start, end, &target, budget, &mut Xoshiro256StarStar::new(23)).unwrap();
+
+    assert!(!found.met);
+    assert!(!target.contains(&found.metrics));
+    assert_eq!((found.generations, found.mutations), (5, 50));
+    assert_eq!(found.metrics, MazeMetrics::measure(&found.maze));
+    assert_perfect(&found.maze);
+
+    assert_eq!(
+        targeted(Generator::Wilson, start, start, &target, budget, &mut Xoshiro256StarStar::new(23)),
+        Err(MazeError::StartIsEnd { x: 0, y: 0 })
+    );
+}
@@ -0,0 +1,69 @@
+//! Wilson's generator, which samples uniformly from all perfect mazes.
+
+use crate::MazeError;
+use crate::rng::MazeRng;
+use crate::wall_maze::{InteriorPosition, WallMaze};
+
+use super::{cells, check_start_end, index, Carving};
+
+/// Generates a perfect maze chosen uniformly at random from all perfect mazes of its size,
+/// using Wilson's algorithm.
+///
+/// The maze starts as the single cell `start`. Each cell not yet in the maze then begins a
+/// random walk which continues until it reaches the maze. Whenever the walk crosses its own
+/// path the loop this makes is erased, and the loop-free path that remains is carved into the
+/// maze. Every spanning tree of the grid, and so every perfect maze, is equally likely,
+/// whichever cell the maze starts from.
+///
+/// Since the first walks wander for a long time before they find the maze, this is slower
+/// than the other generators.
+///
+/// # Errors
+///
+/// Returns an error if the start and end positions are the same.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::generate::wilson;
+/// use maze_solver::rng::Xoshiro256StarStar;
+/// use maze_solver::wall_maze::InteriorPosition;
+///
+/// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+/// let maze = wilson(start, end, &mut Xoshiro256StarStar::new(1)).unwrap();
+///
+/// assert_eq!(maze.passages().count(), 63);
+/// assert!(maze.solve().is_ok());
+/// ```
+pub fn wilson<const WIDTH: usize, const HEIGHT: usize>(
+    start: InteriorPosition<WIDTH, HEIGHT>,
+    end: InteriorPosition<WIDTH, HEIGHT>,
+    rng: &mut impl MazeRng,
+) -> Result<WallMaze<WIDTH, HEIGHT>, MazeError> {
+    check_start_end(start, end)?;
+    let mut carving = Carving::new();
+    let mut in_maze = vec![false; WIDTH * HEIGHT];
+    // The direction the latest walk last left each cell in, which skips any loops it made there
+    let mut next = vec![start; WIDTH * HEIGHT];
+    in_maze[index(start)] = true;
+
+    for cell in cells::<WIDTH, HEIGHT>() {
+        let mut current = cell;
+        while !in_maze[index(current)] {
+            let neighbours = current.adjacent_positions();
+            let step = neighbours[rng.below(neighbours.len())];
+            next[index(current)] = step;
+            current = step;
+        }
+
+        let mut current = cell;
+        while !in_maze[index(current)] {
+            in_maze[index(current)] = true;
+            let step = next[index(current)];
+            carving.carve(current, step);
+            current = step;
+        }
+    }
+    carving.into_maze(start, end)
+}
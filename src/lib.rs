@@ -1,18 +1,33 @@
-use std::{collections::HashMap, hash::Hash};
+use std::hash::Hash;
+
+use solve::Solver;
 
 pub mod wall_maze;
+pub mod block_maze;
+pub mod generate;
+pub mod rng;
+pub mod solve;
+pub mod union_find;
+mod error;
+#[cfg(test)]
+mod test_util;
+pub use error::MazeError;
 
-pub trait MazeCell {
-    fn adjacent_cells(&self) -> impl Iterator<Item = &Self>;
+/// A cell of a maze, able to list the cells next to it regardless of any obstacles.
+///
+/// Neighbours are yielded by value, and the iterator does not borrow the cell it came from,
+/// so that cells which are computed on the fly (such as grid coordinates) can implement
+/// this trait without storing their neighbours anywhere.
+pub trait MazeCell: Sized {
+    fn adjacent_cells(&self) -> impl Iterator<Item = Self> + use<Self>;
 }
 pub fn are_adjacent<T: MazeCell + PartialEq>(cell1: &T, cell2: &T) -> bool {
-    let adjacent = cell1.adjacent_cells();
-    for adj in adjacent {
-        if adj == cell2 {
-            return true;
-        }
-    }
-    false
+    cell1.adjacent_cells().any(|adj| adj == *cell2)
+}
+
+/// Returns `true` if a single step within `maze` leads from `cell1` to `cell2`.
+pub fn are_passable_neighbours<M: Maze>(maze: &M, cell1: &M::Cell, cell2: &M::Cell) -> bool where M::Cell: PartialEq {
+    maze.passable_neighbours(cell1).any(|adj| adj == *cell2)
 }
 
 pub trait PathHeuristic {
@@ -24,15 +39,49 @@ pub trait Maze {
 
     fn start(&self) -> Self::Cell;
     fn end(&self) -> Self::Cell;
+    /// Returns the cells that can be reached from `cell` in a single step of this maze.
+    fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell>;
     fn try_solve(&self) -> Option<Vec<Self::Cell>>;
 
-    fn solve(&self) -> Result<Vec<Self::Cell>, String> {
+    /// Returns the number of cells in the maze, if the maze numbers its cells with `cell_index`.
+    ///
+    /// The solvers in `solve` keep what they know about the cells of such a maze in vectors
+    /// indexed by those numbers, rather than hashing every cell they find.
+    fn cell_count(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the number of a cell of the maze, below `cell_count` and different for every cell,
+    /// or `None` if the maze does not number its cells.
+    fn cell_index(&self, _cell: &Self::Cell) -> Option<usize> {
+        None
+    }
+
+    fn solve(&self) -> Result<Vec<Self::Cell>, MazeError> {
         if let Some(path) = self.try_solve() {
             Ok(path)
         } else {
-            Err("No solution found".to_string())
+            Err(MazeError::Unsolvable)
         }
     }
+
+    /// Finds a path from start to end with as few steps as possible, by breadth-first search.
+    ///
+    /// Unlike `try_solve`, which may return any path, every cell is reached by the shortest
+    /// route to it before any cell further from the start is looked at, so the path found to
+    /// the end is a shortest one. Returns `None` if the end cannot be reached.
+    fn try_solve_shortest(&self) -> Option<Vec<Self::Cell>> where Self::Cell: Hash + Eq + Clone {
+        solve::BreadthFirst.solve(self).path
+    }
+
+    /// Finds a path from start to end with as few steps as possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no path exists from start to end.
+    fn solve_shortest(&self) -> Result<Vec<Self::Cell>, MazeError> where Self::Cell: Hash + Eq + Clone {
+        self.try_solve_shortest().ok_or(MazeError::Unsolvable)
+    }
 }
 
 pub trait MazeWall {
@@ -44,85 +93,34 @@ pub trait MazeWall {
 pub trait WallMaze: Maze {
     type Wall: MazeWall<Cell = Self::Cell>;
 
-    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), String>;
-    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), String>;
-    fn add_interior_wall(&mut self, wall: Self::Wall) -> Result<(), String>;
-    fn remove_interior_wall(&mut self, wall: Self::Wall) -> Result<(), String>;
+    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError>;
+    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError>;
+    fn add_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError>;
+    fn remove_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError>;
     fn separated_by_wall(&self, cell1: &Self::Cell, cell2: &Self::Cell) -> bool;
 
+    /// Finds a path from start to end by depth-first search, which need not be a shortest one.
+    /// See `Maze::try_solve_shortest` for a path with as few steps as possible.
     fn try_solve(&self) -> Option<Vec<Self::Cell>> where Self::Cell: Hash + Eq + Clone {
-        let mut unchecked = vec![self.start()];
-        let mut path_to = HashMap::new();
-        path_to.insert(self.start(), vec![self.start()]);
-        loop {
-            if path_to.contains_key(&self.end()) {
-                break;
-            }
-            if unchecked.is_empty() {
-                return None;
-            }
-            let current = unchecked.pop().unwrap();
-            for adj in current.adjacent_cells() {
-                if path_to.contains_key(&adj) {
-                    continue;
-                }
-                if self.separated_by_wall(&current, &adj) {
-                    continue;
-                }
-                unchecked.push(adj.clone());
-                let mut new_path = path_to.get(&current).unwrap().clone();
-                new_path.push(adj.clone());
-                path_to.insert(adj.clone(), new_path);
-            }
-        }
-        Some(path_to.get(&self.end()).unwrap().clone())
+        solve::DepthFirst.solve(self).path
     }
 }
 
 pub trait HeuristicWallMaze: WallMaze where Self::Cell: PathHeuristic {
+    /// Finds a path from start to end by A* search, guided by the cells' heuristic. The path is
+    /// a shortest one if the heuristic is consistent, as described for `solve::AStar`.
     fn try_solve(&self) -> Option<Vec<Self::Cell>> where Self::Cell: Hash + Eq + Clone {
-        let mut unchecked = vec![self.start()];
-        let mut path_to = HashMap::new();
-        path_to.insert(self.start(), vec![self.start()]);
-        loop {
-            if path_to.contains_key(&self.end()) {
-                break;
-            }
-            if unchecked.is_empty() {
-                return None;
-            }
-            let value = |pos: &Self::Cell| {
-                pos.heuristic(&self.end()) + path_to.get(pos).unwrap().len() as f64
-            };
-            unchecked.sort_by(|a, b| {
-                value(a).total_cmp(&value(b)).reverse()
-            });
-            let current = unchecked.pop().unwrap();
-            for adj in current.adjacent_cells() {
-                if path_to.contains_key(&adj) {
-                    continue;
-                }
-                if self.separated_by_wall(&current, &adj) {
-                    continue;
-                }
-                unchecked.push(adj.clone());
-                let mut new_path = path_to.get(&current).unwrap().clone();
-                new_path.push(adj.clone());
-                path_to.insert(adj.clone(), new_path);
-            }
-        }
-        Some(path_to.get(&self.end()).unwrap().clone())
+        solve::AStar.solve(self).path
     }
-
 }
 
 impl<T: WallMaze> HeuristicWallMaze for T where T::Cell: PathHeuristic {}
 
 pub trait MutSolubleMaze: Maze {
-    fn move_start(&mut self, new_start: Self::Cell) -> Result<(), String>;
+    fn move_start(&mut self, new_start: Self::Cell) -> Result<(), MazeError>;
     fn flip_start_end(&mut self);
 
-    fn move_end(&mut self, new_end: Self::Cell) -> Result<(), String> {
+    fn move_end(&mut self, new_end: Self::Cell) -> Result<(), MazeError> {
         self.flip_start_end();
         match self.move_start(new_end) {
             Ok(_) => {
@@ -138,22 +136,148 @@ pub trait MutSolubleMaze: Maze {
 }
 
 pub trait MutSolubleWallMaze: WallMaze + MutSolubleMaze {
-    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), String>;
-    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), String>;
+    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError>;
+    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError>;
 }
 
 pub trait BlockMaze: Maze {
     fn blocks(&self) -> Vec<Self::Cell>;
+
+    /// Finds a path from start to end by depth-first search, which need not be a shortest one.
+    /// Returns `None` if the start or end is blocked, as neither can then be stood on.
+    fn try_solve(&self) -> Option<Vec<Self::Cell>> where Self::Cell: Hash + Eq + Clone {
+        let blocks = self.blocks();
+        if blocks.contains(&self.start()) || blocks.contains(&self.end()) {
+            return None;
+        }
+        solve::DepthFirst.solve(self).path
+    }
 }
 
 pub trait MutSolubleBlockMaze: BlockMaze + MutSolubleMaze {
-    fn add_block(&mut self, block: Self::Cell) -> Result<(), String>;
-    fn remove_block(&mut self, block: Self::Cell) -> Result<(), String>;
+    fn add_block(&mut self, block: Self::Cell) -> Result<(), MazeError>;
+    fn remove_block(&mut self, block: Self::Cell) -> Result<(), MazeError>;
 }
 
 
 
 #[cfg(test)]
 mod tests {
-    
+    use super::*;
+
+    /// A single row of cells numbered `0..LENGTH`, with walls identified by the cell to their left.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct Slot<const LENGTH: usize>(usize);
+
+    impl<const LENGTH: usize> MazeCell for Slot<LENGTH> {
+        fn adjacent_cells(&self) -> impl Iterator<Item = Self> + use<LENGTH> {
+            let left = self.0.checked_sub(1).map(Slot);
+            let right = (self.0 + 1 < LENGTH).then_some(Slot(self.0 + 1));
+            left.into_iter().chain(right)
+        }
+    }
+
+    impl<const LENGTH: usize> PathHeuristic for Slot<LENGTH> {
+        fn heuristic(&self, other: &Self) -> f64 {
+            self.0.abs_diff(other.0) as f64
+        }
+    }
+
+    impl<const LENGTH: usize> MazeWall for Slot<LENGTH> {
+        type Cell = Slot<LENGTH>;
+
+        fn surrounding_cells(&self) -> [Self::Cell; 2] {
+            [*self, Slot(self.0 + 1)]
+        }
+    }
+
+    struct Corridor<const LENGTH: usize> {
+        walls: Vec<Slot<LENGTH>>,
+    }
+
+    impl<const LENGTH: usize> Maze for Corridor<LENGTH> {
+        type Cell = Slot<LENGTH>;
+
+        fn start(&self) -> Self::Cell {
+            Slot(0)
+        }
+
+        fn end(&self) -> Self::Cell {
+            Slot(LENGTH - 1)
+        }
+
+        fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell> {
+            let cell = *cell;
+            cell.adjacent_cells().filter(move |adj| !self.separated_by_wall(&cell, adj))
+        }
+
+        fn try_solve(&self) -> Option<Vec<Self::Cell>> {
+            WallMaze::try_solve(self)
+        }
+    }
+
+    impl<const LENGTH: usize> WallMaze for Corridor<LENGTH> {
+        type Wall = Slot<LENGTH>;
+
+        fn add_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+            self.walls.push(wall);
+            Ok(())
+        }
+
+        fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+            self.walls.retain(|w| *w != wall);
+            Ok(())
+        }
+
+        fn add_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+            WallMaze::add_wall(self, wall)
+        }
+
+        fn remove_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+            WallMaze::remove_wall(self, wall)
+        }
+
+        fn separated_by_wall(&self, cell1: &Self::Cell, cell2: &Self::Cell) -> bool {
+            self.walls.iter().any(|wall| {
+                let [left, right] = wall.surrounding_cells();
+                (left == *cell1 && right == *cell2) || (left == *cell2 && right == *cell1)
+            })
+        }
+    }
+
+    #[test]
+    fn test_value_cells_adjacency() {
+        assert!(are_adjacent(&Slot::<4>(1), &Slot(2)));
+        assert!(!are_adjacent(&Slot::<4>(1), &Slot(3)));
+        assert_eq!(Slot::<4>(3).adjacent_cells().collect::<Vec<_>>(), vec![Slot(2)]);
+
+        let mut corridor = Corridor::<4> { walls: Vec::new() };
+        assert!(are_passable_neighbours(&corridor, &Slot(1), &Slot(2)));
+        WallMaze::add_wall(&mut corridor, Slot(1)).unwrap();
+        assert!(!are_passable_neighbours(&corridor, &Slot(1), &Slot(2)));
+        assert!(are_passable_neighbours(&corridor, &Slot(1), &Slot(0)));
+    }
+
+    #[test]
+    fn test_default_solvers_on_value_cells() {
+        let mut corridor = Corridor::<4> { walls: Vec::new() };
+        let expected = vec![Slot(0), Slot(1), Slot(2), Slot(3)];
+        assert_eq!(Maze::try_solve(&corridor), Some(expected.clone()));
+        assert_eq!(HeuristicWallMaze::try_solve(&corridor), Some(expected));
+
+        WallMaze::add_wall(&mut corridor, Slot(2)).unwrap();
+        assert_eq!(Maze::try_solve(&corridor), None);
+        assert_eq!(HeuristicWallMaze::try_solve(&corridor), None);
+        assert!(Maze::solve(&corridor).is_err());
+    }
+
+    #[test]
+    fn test_shortest_solver_on_value_cells() {
+        let mut corridor = Corridor::<4> { walls: Vec::new() };
+        assert_eq!(corridor.try_solve_shortest(), Some(vec![Slot(0), Slot(1), Slot(2), Slot(3)]));
+
+        WallMaze::add_wall(&mut corridor, Slot(0)).unwrap();
+        assert_eq!(corridor.try_solve_shortest(), None);
+        assert_eq!(corridor.solve_shortest(), Err(MazeError::Unsolvable));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+//! Seedable pseudo-random number generation for the maze generators.
+//!
+//! The generators take their randomness from any implementation of `MazeRng`, so that a maze
+//! can be reproduced exactly from the seed it was generated with. The crate provides
+//! `Xoshiro256StarStar`, whose output for a given seed is fixed: the same seed produces the
+//! same sequence of numbers, and so the same mazes, on every platform and in every version
+//! of the crate.
+//!
+//! # Examples
+//!
+//! ```
+//! use maze_solver::generate::recursive_backtracker;
+//! use maze_solver::rng::Xoshiro256StarStar;
+//! use maze_solver::wall_maze::InteriorPosition;
+//!
+//! let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+//! let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+//!
+//! let maze1 = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(7)).unwrap();
+//! let maze2 = recursive_backtracker(start, end, &mut Xoshiro256StarStar::new(7)).unwrap();
+//! assert_eq!(maze1, maze2);
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+#[cfg(test)]
+mod tests;
+
+/// A source of random numbers for generating mazes.
+///
+/// Implementors only provide `next_u64`. The other methods are derived from it by fixed
+/// algorithms, which are part of the crate's reproducibility guarantee and will not change.
+pub trait MazeRng {
+    /// Returns the next 64 random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a number uniformly distributed in `0..bound`.
+    ///
+    /// This uses Lemire's multiply-and-reject method on `next_u64`, so it is free of modulo bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "cannot pick a number below zero");
+        let bound = bound as u64;
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = self.next_u64() as u128 * bound as u128;
+            if product as u64 >= threshold {
+                return (product >> 64) as usize;
+            }
+        }
+    }
+
+    /// Returns a number uniformly distributed in `[0, 1)`, with 53 bits of precision.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns `true` with the given probability.
+    ///
+    /// Probabilities of 0 or less never return `true`, and probabilities of 1 or more always do.
+    fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// Returns a uniformly chosen element of `items`, or `None` if it is empty.
+    fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> where Self: Sized {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len())])
+        }
+    }
+
+    /// Shuffles `items` into a uniformly random order, using the Fisher–Yates shuffle.
+    fn shuffle<T>(&mut self, items: &mut [T]) where Self: Sized {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// The xoshiro256** generator of Blackman and Vigna, seeded with SplitMix64.
+///
+/// This is a fast, small-state generator with good statistical quality. It is not suitable
+/// for cryptography.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::rng::{MazeRng, Xoshiro256StarStar};
+///
+/// let mut rng = Xoshiro256StarStar::new(2024);
+/// let roll = rng.below(6);
+/// assert!(roll < 6);
+///
+/// // The same seed always gives the same numbers
+/// assert_eq!(Xoshiro256StarStar::new(2024).below(6), roll);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro256StarStar {
+    state: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Creates a generator from a seed.
+    ///
+    /// The seed is expanded into the 256-bit state by the SplitMix64 generator, as recommended
+    /// by the authors of xoshiro, so that similar seeds give unrelated sequences.
+    pub fn new(seed: u64) -> Self {
+        let mut splitmix = seed;
+        let mut state = [0; 4];
+        for word in &mut state {
+            *word = split_mix_64(&mut splitmix);
+        }
+        Self { state }
+    }
+
+    /// Creates a generator with a seed drawn from the random keys the standard library uses
+    /// for hashing. Its output differs from run to run and cannot be reproduced.
+    pub fn from_entropy() -> Self {
+        Self::new(RandomState::new().hash_one(0u64))
+    }
+}
+
+impl MazeRng for Xoshiro256StarStar {
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+impl<R: MazeRng + ?Sized> MazeRng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// Advances a SplitMix64 state and returns its next output.
+fn split_mix_64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
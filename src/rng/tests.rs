@@ -0,0 +1,70 @@
+use super::*;
+
+#[test]
+fn test_split_mix_64_reference_output() {
+    let mut state = 0;
+    let outputs: Vec<u64> = (0..4).map(|_| split_mix_64(&mut state)).collect();
+    assert_eq!(outputs, vec![0xe220a8397b1dcdaf, 0x6e789e6aa1b965f4, 0x06c45d188009454f, 0xf88bb8a8724c81ec]);
+}
+
+#[test]
+fn test_xoshiro_reference_output() {
+    let mut rng = Xoshiro256StarStar { state: [1, 2, 3, 4] };
+    let outputs: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+    assert_eq!(outputs, vec![11520, 0, 1509978240, 1215971899390074240]);
+}
+
+
+// The outputs below are golden values: if any of these tests fails, seeded mazes will no longer
+// match those generated by earlier versions of the crate.
+
+#[test]
+fn test_xoshiro_seeded_output() {
+    let mut rng = Xoshiro256StarStar::new(0);
+    let outputs: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+    assert_eq!(outputs, vec![0x99ec5f36cb75f2b4, 0xbf6e1f784956452a, 0x1a5f849d4933e6e0, 0x6aa594f1262d2d2c]);
+}
+
+#[test]
+fn test_derived_methods_output() {
+    let mut rng = Xoshiro256StarStar::new(1);
+    let below: Vec<usize> = (0..12).map(|_| rng.below(10)).collect();
+    assert_eq!(below, vec![7, 5, 5, 3, 6, 1, 0, 3, 8, 5, 9, 9]);
+
+    let mut rng = Xoshiro256StarStar::new(2);
+    let floats: Vec<f64> = (0..3).map(|_| rng.next_f64()).collect();
+    assert_eq!(floats, vec![0.10217911323039464, 0.725517288515156, 0.18396244547340834]);
+
+    let mut rng = Xoshiro256StarStar::new(3);
+    let mut items: Vec<u32> = (0..10).collect();
+    rng.shuffle(&mut items);
+    assert_eq!(items, vec![9, 4, 8, 0, 7, 2, 3, 1, 5, 6]);
+
+    let mut rng = Xoshiro256StarStar::new(4);
+    let chances: Vec<bool> = (0..12).map(|_| rng.chance(0.3)).collect();
+    assert_eq!(chances, vec![true, false, false, false, true, false, false, false, true, false, true, false]);
+}
+
+#[test]
+fn test_below_is_in_range_and_covers_it() {
+    let mut rng = Xoshiro256StarStar::new(5);
+    let mut counts = [0; 7];
+    for _ in 0..7000 {
+        counts[rng.below(7)] += 1;
+    }
+    assert!(counts.iter().all(|&count| (850..1150).contains(&count)), "{:?}", counts);
+    assert_eq!(rng.below(1), 0);
+    assert!(rng.choose::<u8>(&[]).is_none());
+}
+
+#[test]
+fn test_rng_through_mutable_reference() {
+    fn draw(mut rng: impl MazeRng) -> Vec<u64> {
+        (0..3).map(|_| rng.next_u64()).collect()
+    }
+
+    let mut rng = Xoshiro256StarStar::new(6);
+    let mut copy = rng.clone();
+    assert_eq!(draw(&mut rng), (0..3).map(|_| copy.next_u64()).collect::<Vec<_>>());
+    assert_eq!(rng, copy);
+}
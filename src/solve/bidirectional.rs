@@ -0,0 +1,133 @@
+//! Bidirectional breadth-first search.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Instant;
+
+use crate::Maze;
+
+use super::{Solution, Solver, SolverStats};
+
+/// Bidirectional breadth-first search, which searches out from the start and the end at once
+/// and always finds a shortest path.
+///
+/// Each search expands a whole layer of cells at a time, the one with the smaller layer going
+/// next, and the two stop as soon as a layer reaches a cell the other has found. In open areas,
+/// where the number of cells within a given distance of a cell grows with the distance, each
+/// search need only reach half way, and around half as many cells are expanded as by
+/// `BreadthFirst`. Where the edges of the maze or corridors hem the searches in, as for a path
+/// between opposite corners of an empty maze, there is less to gain.
+///
+/// Searching back from the end relies on every passage of the maze leading both ways, that
+/// is, on `cell2` being a passable neighbour of `cell1` exactly when `cell1` is a passable
+/// neighbour of `cell2`. This holds for wall mazes and block mazes alike.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::solve::{Bidirectional, BreadthFirst, Solver};
+/// use maze_solver::wall_maze::{WallMaze, InteriorPosition};
+///
+/// let start = InteriorPosition::<60, 60>::new(20, 30).unwrap();
+/// let end = InteriorPosition::<60, 60>::new(40, 30).unwrap();
+/// let maze = WallMaze::<60, 60>::new(start, end).unwrap();
+///
+/// let bidirectional = Bidirectional.solve(&maze);
+/// let breadth_first = BreadthFirst.solve(&maze);
+/// assert_eq!(bidirectional.path.unwrap().len(), breadth_first.path.unwrap().len());
+/// assert!(bidirectional.stats.expanded * 2 < breadth_first.stats.expanded);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bidirectional;
+
+/// One of the two searches, from the start or from the end.
+struct Side<C> {
+    /// The distance of each cell found from the cell the search began at, and the cell it
+    /// was found from.
+    seen: HashMap<C, (usize, Option<C>)>,
+    /// The cells found furthest from the cell the search began at, yet to be expanded.
+    layer: Vec<C>,
+}
+
+impl<C: Hash + Eq + Clone> Side<C> {
+    fn new(cell: C) -> Self {
+        Self { seen: HashMap::from([(cell.clone(), (0, None))]), layer: vec![cell] }
+    }
+
+    /// Returns the path from `cell` back to the cell the search began at.
+    fn path_back(&self, cell: &C) -> Vec<C> {
+        let mut path = vec![cell.clone()];
+        while let Some((_, Some(previous))) = self.seen.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path
+    }
+}
+
+impl<M: Maze + ?Sized> Solver<M> for Bidirectional where M::Cell: Hash + Eq + Clone {
+    fn name(&self) -> &'static str {
+        "bidirectional"
+    }
+
+    fn solve(&self, maze: &M) -> Solution<M::Cell> {
+        let started = Instant::now();
+        let mut stats = SolverStats::default();
+        let path = search(maze, &mut stats);
+        stats.path_length = path.as_ref().map(|path| path.len() - 1);
+        stats.elapsed = started.elapsed();
+        Solution { path, stats }
+    }
+}
+
+/// Runs the two searches until they meet, returning the path found and counting the work done.
+fn search<M: Maze + ?Sized>(maze: &M, stats: &mut SolverStats) -> Option<Vec<M::Cell>> where M::Cell: Hash + Eq + Clone {
+    let (start, end) = (maze.start(), maze.end());
+    if start == end {
+        stats.discovered = 1;
+        stats.peak_frontier = 1;
+        return Some(vec![start]);
+    }
+
+    let mut forward = Side::new(start);
+    let mut backward = Side::new(end);
+    stats.discovered = 2;
+    stats.peak_frontier = 2;
+    while !forward.layer.is_empty() && !backward.layer.is_empty() {
+        let forward_turn = forward.layer.len() <= backward.layer.len();
+        let (this, other) = if forward_turn { (&mut forward, &backward) } else { (&mut backward, &forward) };
+
+        // Finish the whole layer before stopping, as a later cell in it may give a shorter path
+        let mut meeting: Option<(usize, M::Cell)> = None;
+        let layer = std::mem::take(&mut this.layer);
+        let layer_size = layer.len();
+        for (i, cell) in layer.into_iter().enumerate() {
+            stats.expanded += 1;
+            let distance = this.seen[&cell].0 + 1;
+            for adj in maze.passable_neighbours(&cell) {
+                if this.seen.contains_key(&adj) {
+                    continue;
+                }
+                this.seen.insert(adj.clone(), (distance, Some(cell.clone())));
+                match other.seen.get(&adj) {
+                    Some(&(other_distance, _)) => {
+                        if meeting.as_ref().is_none_or(|(length, _)| distance + other_distance < *length) {
+                            meeting = Some((distance + other_distance, adj.clone()));
+                        }
+                    },
+                    None => stats.discovered += 1,
+                }
+                this.layer.push(adj);
+            }
+            let frontier = layer_size - i - 1 + this.layer.len() + other.layer.len();
+            stats.peak_frontier = stats.peak_frontier.max(frontier);
+        }
+
+        if let Some((_, cell)) = meeting {
+            let mut path = forward.path_back(&cell);
+            path.reverse();
+            path.extend(backward.path_back(&cell).into_iter().skip(1));
+            return Some(path);
+        }
+    }
+    None
+}
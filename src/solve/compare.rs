@@ -0,0 +1,94 @@
+//! Side-by-side measurements of several solvers on the same maze.
+
+use std::fmt;
+use std::hash::Hash;
+
+use crate::{Maze, PathHeuristic};
+
+use super::{SolverRegistry, SolverStats};
+
+/// The work done by each of a number of solvers on the same maze, as made by
+/// `SolverRegistry::compare` and `compare`.
+///
+/// Displaying the table lays it out with a row per solver, in the order they were run.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ComparisonTable {
+    rows: Vec<(&'static str, SolverStats)>,
+}
+
+impl ComparisonTable {
+    /// Returns the name of each solver run and the work it did, in the order they were run.
+    pub fn get_rows(&self) -> &[(&'static str, SolverStats)] {
+        &self.rows
+    }
+
+    /// Returns the work done by the solver with the given name, if it was run.
+    pub fn get(&self, name: &str) -> Option<&SolverStats> {
+        self.rows.iter().find(|(row_name, _)| *row_name == name).map(|(_, stats)| stats)
+    }
+}
+
+impl fmt::Display for ComparisonTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const HEADINGS: [&str; 6] = ["solver", "path length", "expanded", "discovered", "peak frontier", "elapsed"];
+        let cells: Vec<[String; 6]> = self.rows.iter().map(|(name, stats)| [
+            name.to_string(),
+            stats.path_length.map_or_else(|| "-".to_string(), |length| length.to_string()),
+            stats.expanded.to_string(),
+            stats.discovered.to_string(),
+            stats.peak_frontier.to_string(),
+            format!("{:.1?}", stats.elapsed),
+        ]).collect();
+
+        let mut widths = HEADINGS.map(str::len);
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let write_row = |f: &mut fmt::Formatter<'_>, row: [&str; 6]| {
+            // The solver names are left aligned and the numbers right aligned
+            write!(f, "{:<width$}", row[0], width = widths[0])?;
+            for (cell, width) in row.iter().zip(widths).skip(1) {
+                write!(f, "  {cell:>width$}")?;
+            }
+            writeln!(f)
+        };
+        write_row(f, HEADINGS)?;
+        for row in &cells {
+            write_row(f, row.each_ref().map(String::as_str))?;
+        }
+        Ok(())
+    }
+}
+
+impl<M: Maze + ?Sized> SolverRegistry<M> {
+    /// Runs every registered solver on the maze, in the order they were registered, and
+    /// collects the work each did.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::solve::SolverRegistry;
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition};
+    ///
+    /// let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+    /// let maze = WallMaze::<8, 8>::new(start, end).unwrap();
+    ///
+    /// let table = SolverRegistry::builtin().compare(&maze);
+    /// assert_eq!(table.get_rows().len(), 6);
+    /// assert_eq!(table.get("breadth-first").unwrap().path_length, Some(14));
+    /// println!("{table}");
+    /// ```
+    pub fn compare(&self, maze: &M) -> ComparisonTable {
+        ComparisonTable { rows: self.iter().map(|solver| (solver.name(), solver.solve(maze).stats)).collect() }
+    }
+}
+
+/// Runs every built-in solver on the maze and collects the work each did, as
+/// `SolverRegistry::builtin().compare(maze)` does.
+pub fn compare<M: Maze + ?Sized>(maze: &M) -> ComparisonTable where M::Cell: Hash + Eq + Clone + PathHeuristic {
+    SolverRegistry::builtin().compare(maze)
+}
@@ -0,0 +1,211 @@
+//! Interchangeable algorithms for solving mazes.
+//!
+//! A `Solver` finds a path from the start to the end of any `Maze`, and reports how much work
+//! it did along the way in `SolverStats`. The built-in solvers all explore the maze through
+//! `Maze::passable_neighbours`, and differ in the order in which they visit its cells:
+//! - `DepthFirst` follows one route as far as it goes before backtracking, and may find a long path
+//! - `BreadthFirst` visits cells in order of their distance from the start, and finds a shortest path
+//! - `Dijkstra` also finds a shortest path, visiting cells in order of distance with a priority queue
+//! - `AStar` finds a shortest path, guided towards the end by the cells' `PathHeuristic`
+//! - `GreedyBestFirst` heads straight for the end by the heuristic alone, and may find a long path
+//! - `Bidirectional` runs breadth-first searches from the start and the end until they meet, and
+//!   finds a shortest path
+//!
+//! `AStar` never reopens a cell it has expanded, so it finds a shortest path whenever the
+//! heuristic is consistent: it never overestimates the number of steps to the end, and never
+//! decreases by more than one per step. This is the case for the Manhattan distance used by
+//! positions in a grid.
+//!
+//! A `SolverRegistry` holds solvers under their names, so that the one to use can be chosen at
+//! runtime, and `compare` runs them all on the same maze to set their `SolverStats` side by side.
+
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::{Maze, PathHeuristic};
+
+#[cfg(test)]
+mod tests;
+
+mod bidirectional;
+mod compare;
+mod registry;
+mod search;
+pub use bidirectional::Bidirectional;
+pub use compare::{compare, ComparisonTable};
+pub use registry::SolverRegistry;
+pub use search::SearchSteps;
+
+use search::{Search, Strategy};
+
+/// An algorithm for finding a path from the start to the end of mazes of type `M`.
+///
+/// The trait is object safe, so solvers for the same type of maze can be boxed and chosen
+/// between at runtime, as `SolverRegistry` does.
+pub trait Solver<M: Maze + ?Sized> {
+    /// Returns the name of the solver, in lowercase words separated by hyphens.
+    fn name(&self) -> &'static str;
+
+    /// Searches the maze for a path from its start to its end.
+    fn solve(&self, maze: &M) -> Solution<M::Cell>;
+}
+
+/// Measurements of the work done by a solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SolverStats {
+    /// The number of cells whose neighbours were looked at. The end of the maze is never expanded.
+    pub expanded: usize,
+    /// The number of distinct cells found, including the start.
+    pub discovered: usize,
+    /// The largest number of cells waiting in the frontier at once, that is, found but not yet
+    /// expanded. A cell counts once however many times it has been moved in the frontier.
+    pub peak_frontier: usize,
+    /// The number of steps in the path found, or `None` if no path was found.
+    pub path_length: Option<usize>,
+    /// The time taken to find the path.
+    pub elapsed: Duration,
+}
+
+/// The result of running a solver on a maze.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution<C> {
+    /// The path found from the start to the end, including both, or `None` if the end cannot be reached.
+    pub path: Option<Vec<C>>,
+    /// The work done finding the path.
+    pub stats: SolverStats,
+}
+
+/// A single step of a search, for following its progress, as yielded by `SearchSteps` and by
+/// `WallMaze::solve_steps`.
+///
+/// A search begins by discovering the start, and each cell it expands is followed by the cells
+/// that expanding it discovered. After the start and after each expanded cell comes a snapshot
+/// of the frontier, and the search finishes with `Found` or `Failed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveEvent<C> {
+    /// The cell was added to the frontier, either for the first time or because a shorter route
+    /// to it was found.
+    Discovered(C),
+    /// The cell was taken from the frontier and its neighbours looked at.
+    Expanded(C),
+    /// The cells waiting in the frontier, in the order they will be taken from it.
+    Frontier(Vec<C>),
+    /// The search reached the end along the given path, which includes the start and the end.
+    Found(Vec<C>),
+    /// The search ran out of cells to expand without reaching the end.
+    Failed,
+}
+
+/// Depth-first search, which finds a path but not necessarily a shortest one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DepthFirst;
+
+impl DepthFirst {
+    /// Returns the steps the solver takes on the maze, one at a time.
+    pub fn steps<M: Maze + ?Sized>(maze: &M) -> SearchSteps<'_, M> where M::Cell: Hash + Eq + Clone {
+        SearchSteps::new(Search::uninformed(maze, Strategy::DepthFirst))
+    }
+}
+
+/// Breadth-first search, which always finds a shortest path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BreadthFirst;
+
+impl BreadthFirst {
+    /// Returns the steps the solver takes on the maze, one at a time.
+    pub fn steps<M: Maze + ?Sized>(maze: &M) -> SearchSteps<'_, M> where M::Cell: Hash + Eq + Clone {
+        SearchSteps::new(Search::uninformed(maze, Strategy::BreadthFirst))
+    }
+}
+
+/// Dijkstra's algorithm, which always finds a shortest path.
+///
+/// Every step of a maze has the same cost, so this visits cells in the same order of distance
+/// as `BreadthFirst`, but through a priority queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Dijkstra;
+
+impl Dijkstra {
+    /// Returns the steps the solver takes on the maze, one at a time.
+    pub fn steps<M: Maze + ?Sized>(maze: &M) -> SearchSteps<'_, M> where M::Cell: Hash + Eq + Clone {
+        SearchSteps::new(Search::uninformed(maze, Strategy::Dijkstra))
+    }
+}
+
+/// A* search, which uses the heuristic distance to the end to visit fewer cells than
+/// `BreadthFirst`, and finds a shortest path as long as the heuristic is consistent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AStar;
+
+impl AStar {
+    /// Returns the steps the solver takes on the maze, one at a time.
+    pub fn steps<M: Maze + ?Sized>(maze: &M) -> SearchSteps<'_, M> where M::Cell: Hash + Eq + Clone + PathHeuristic {
+        let end = maze.end();
+        SearchSteps::new(Search::new(maze, Strategy::AStar, move |cell: &M::Cell| cell.heuristic(&end)))
+    }
+}
+
+/// Greedy best-first search, which always visits the cell heuristically closest to the end
+/// next. It is often quick, but need not find a shortest path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GreedyBestFirst;
+
+impl GreedyBestFirst {
+    /// Returns the steps the solver takes on the maze, one at a time.
+    pub fn steps<M: Maze + ?Sized>(maze: &M) -> SearchSteps<'_, M> where M::Cell: Hash + Eq + Clone + PathHeuristic {
+        let end = maze.end();
+        SearchSteps::new(Search::new(maze, Strategy::Greedy, move |cell: &M::Cell| cell.heuristic(&end)))
+    }
+}
+
+impl<M: Maze + ?Sized> Solver<M> for DepthFirst where M::Cell: Hash + Eq + Clone {
+    fn name(&self) -> &'static str {
+        "depth-first"
+    }
+
+    fn solve(&self, maze: &M) -> Solution<M::Cell> {
+        Search::uninformed(maze, Strategy::DepthFirst).run()
+    }
+}
+
+impl<M: Maze + ?Sized> Solver<M> for BreadthFirst where M::Cell: Hash + Eq + Clone {
+    fn name(&self) -> &'static str {
+        "breadth-first"
+    }
+
+    fn solve(&self, maze: &M) -> Solution<M::Cell> {
+        Search::uninformed(maze, Strategy::BreadthFirst).run()
+    }
+}
+
+impl<M: Maze + ?Sized> Solver<M> for Dijkstra where M::Cell: Hash + Eq + Clone {
+    fn name(&self) -> &'static str {
+        "dijkstra"
+    }
+
+    fn solve(&self, maze: &M) -> Solution<M::Cell> {
+        Search::uninformed(maze, Strategy::Dijkstra).run()
+    }
+}
+
+impl<M: Maze + ?Sized> Solver<M> for AStar where M::Cell: Hash + Eq + Clone + PathHeuristic {
+    fn name(&self) -> &'static str {
+        "a-star"
+    }
+
+    fn solve(&self, maze: &M) -> Solution<M::Cell> {
+        let end = maze.end();
+        Search::new(maze, Strategy::AStar, move |cell: &M::Cell| cell.heuristic(&end)).run()
+    }
+}
+
+impl<M: Maze + ?Sized> Solver<M> for GreedyBestFirst where M::Cell: Hash + Eq + Clone + PathHeuristic {
+    fn name(&self) -> &'static str {
+        "greedy-best-first"
+    }
+
+    fn solve(&self, maze: &M) -> Solution<M::Cell> {
+        let end = maze.end();
+        Search::new(maze, Strategy::Greedy, move |cell: &M::Cell| cell.heuristic(&end)).run()
+    }
+}
@@ -0,0 +1,86 @@
+//! Solvers looked up by name.
+
+use std::hash::Hash;
+
+use crate::{Maze, PathHeuristic};
+
+use super::{AStar, Bidirectional, BreadthFirst, DepthFirst, Dijkstra, GreedyBestFirst, Solver};
+
+/// A collection of solvers for mazes of type `M`, each under its own name.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::solve::SolverRegistry;
+/// use maze_solver::wall_maze::{WallMaze, InteriorPosition};
+///
+/// let start = InteriorPosition::<5, 5>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<5, 5>::new(4, 4).unwrap();
+/// let maze = WallMaze::<5, 5>::new(start, end).unwrap();
+///
+/// let registry = SolverRegistry::builtin();
+/// let name = "breadth-first"; // read from the command line, say
+/// let solver = registry.get(name).unwrap();
+/// assert_eq!(solver.solve(&maze).path.unwrap().len(), 9);
+///
+/// assert!(registry.get("no-such-solver").is_none());
+/// ```
+pub struct SolverRegistry<M: Maze + ?Sized> {
+    solvers: Vec<Box<dyn Solver<M>>>,
+}
+
+impl<M: Maze + ?Sized> SolverRegistry<M> {
+    /// Creates a registry with no solvers in it.
+    pub fn new() -> Self {
+        Self { solvers: Vec::new() }
+    }
+
+    /// Adds a solver to the registry under its name, returning the solver it replaces if one
+    /// was already registered under that name.
+    pub fn register(&mut self, solver: impl Solver<M> + 'static) -> Option<Box<dyn Solver<M>>> {
+        let solver: Box<dyn Solver<M>> = Box::new(solver);
+        match self.solvers.iter_mut().find(|registered| registered.name() == solver.name()) {
+            Some(registered) => Some(std::mem::replace(registered, solver)),
+            None => {
+                self.solvers.push(solver);
+                None
+            },
+        }
+    }
+
+    /// Returns the solver registered under the given name, if there is one.
+    pub fn get(&self, name: &str) -> Option<&dyn Solver<M>> {
+        self.solvers.iter().find(|solver| solver.name() == name).map(|solver| solver.as_ref())
+    }
+
+    /// Returns the names of the registered solvers, in the order they were registered.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.solvers.iter().map(|solver| solver.name())
+    }
+
+    /// Iterates over the registered solvers, in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solver<M>> {
+        self.solvers.iter().map(|solver| solver.as_ref())
+    }
+}
+
+impl<M: Maze + ?Sized> SolverRegistry<M> where M::Cell: Hash + Eq + Clone + PathHeuristic {
+    /// Creates a registry holding every built-in solver: "depth-first", "breadth-first",
+    /// "dijkstra", "a-star", "greedy-best-first" and "bidirectional".
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register(DepthFirst);
+        registry.register(BreadthFirst);
+        registry.register(Dijkstra);
+        registry.register(AStar);
+        registry.register(GreedyBestFirst);
+        registry.register(Bidirectional);
+        registry
+    }
+}
+
+impl<M: Maze + ?Sized> Default for SolverRegistry<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,378 @@
+//! The search shared by every built-in solver, which differ only in the order they take cells from the frontier.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::time::Instant;
+
+use crate::Maze;
+
+use super::{SolveEvent, Solution, SolverStats};
+
+/// The order in which a search takes cells from its frontier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Strategy {
+    /// The most recently discovered cell first.
+    DepthFirst,
+    /// The earliest discovered cell first.
+    BreadthFirst,
+    /// The cell with the fewest steps from the start first.
+    Dijkstra,
+    /// The cell with the smallest sum of steps from the start and heuristic distance to the end
+    /// first, breaking ties in favour of the cell closest to the end.
+    AStar,
+    /// The cell with the smallest heuristic distance to the end first.
+    Greedy,
+}
+
+impl Strategy {
+    /// Returns `true` if a cell already in the frontier should be moved when a shorter route
+    /// to it is found, which keeps the paths found by the strategy shortest ones.
+    fn reopens(self) -> bool {
+        matches!(self, Strategy::Dijkstra | Strategy::AStar)
+    }
+}
+
+/// The position of a cell in a priority queue frontier. Smaller priorities are taken first,
+/// and among equal priorities the cell pushed first is taken first.
+#[derive(Debug, Clone, Copy)]
+struct Priority {
+    primary: f64,
+    tie_break: f64,
+    order: usize,
+}
+
+impl PartialEq for Priority {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Priority {}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.primary.total_cmp(&other.primary)
+            .then_with(|| self.tie_break.total_cmp(&other.tie_break))
+            .then_with(|| self.order.cmp(&other.order))
+    }
+}
+
+/// The cells waiting to be expanded, given by their index in `Search::cells`.
+#[derive(Debug)]
+enum Frontier {
+    Stack(Vec<usize>),
+    Queue(VecDeque<usize>),
+    Heap(BinaryHeap<Reverse<(Priority, usize)>>),
+}
+
+impl Frontier {
+    fn pop(&mut self) -> Option<usize> {
+        match self {
+            Frontier::Stack(stack) => stack.pop(),
+            Frontier::Queue(queue) => queue.pop_front(),
+            Frontier::Heap(heap) => heap.pop().map(|Reverse((_, id))| id),
+        }
+    }
+}
+
+/// The numbers a search has given the cells it has found.
+enum Ids<C> {
+    /// Looked up by hashing the cells.
+    Hashed(HashMap<C, usize>),
+    /// Indexed by `Maze::cell_index`, with `usize::MAX` for cells not yet found, for mazes that
+    /// number their cells.
+    Dense(Vec<usize>),
+}
+
+/// An estimate of the number of steps from a cell to the end of the maze.
+type Heuristic<'a, C> = Box<dyn Fn(&C) -> f64 + 'a>;
+
+/// The outcome of a single step of a search.
+enum Progress<C> {
+    Searching,
+    /// The search is over, having found the given path or, if `None`, having run out of cells.
+    Finished(Option<Vec<C>>),
+}
+
+/// A search from the start of a maze to its end, run a cell at a time.
+///
+/// Cells are numbered in the order they are discovered, and the bookkeeping for each is kept
+/// in vectors indexed by that number, so cells need only be looked up when they are reached
+/// from a neighbour. Cells of mazes that number their cells themselves are never hashed.
+pub(crate) struct Search<'a, M: Maze + ?Sized> {
+    maze: &'a M,
+    strategy: Strategy,
+    heuristic: Heuristic<'a, M::Cell>,
+    end: M::Cell,
+    ids: Ids<M::Cell>,
+    cells: Vec<M::Cell>,
+    parent: Vec<Option<usize>>,
+    distance: Vec<usize>,
+    closed: Vec<bool>,
+    frontier: Frontier,
+    /// The number of cells discovered but not yet expanded, which leaves out stale entries for
+    /// cells that were moved in or already taken from the frontier.
+    open: usize,
+    pushed: usize,
+    stats: SolverStats,
+    /// The events of the search not yet handed out, if they are being recorded.
+    events: Option<VecDeque<SolveEvent<M::Cell>>>,
+    finished: bool,
+}
+
+impl<'a, M: Maze + ?Sized> Search<'a, M> where M::Cell: Hash + Eq + Clone {
+    /// Starts a search of the maze, using `heuristic` to estimate the number of steps from a
+    /// cell to the end. The heuristic is only used by `Strategy::AStar` and `Strategy::Greedy`.
+    pub(crate) fn new(maze: &'a M, strategy: Strategy, heuristic: impl Fn(&M::Cell) -> f64 + 'a) -> Self {
+        let frontier = match strategy {
+            Strategy::DepthFirst => Frontier::Stack(Vec::new()),
+            Strategy::BreadthFirst => Frontier::Queue(VecDeque::new()),
+            Strategy::Dijkstra | Strategy::AStar | Strategy::Greedy => Frontier::Heap(BinaryHeap::new()),
+        };
+        let (ids, capacity) = match maze.cell_count() {
+            Some(count) => (Ids::Dense(vec![usize::MAX; count]), count),
+            None => (Ids::Hashed(HashMap::new()), 0),
+        };
+        let mut search = Self {
+            maze,
+            strategy,
+            heuristic: Box::new(heuristic),
+            end: maze.end(),
+            ids,
+            cells: Vec::with_capacity(capacity),
+            parent: Vec::with_capacity(capacity),
+            distance: Vec::with_capacity(capacity),
+            closed: Vec::with_capacity(capacity),
+            frontier,
+            open: 0,
+            pushed: 0,
+            stats: SolverStats::default(),
+            events: None,
+            finished: false,
+        };
+        search.discover(maze.start(), None);
+        search
+    }
+
+    /// Starts a search of the maze that has no use for a heuristic.
+    pub(crate) fn uninformed(maze: &'a M, strategy: Strategy) -> Self {
+        Self::new(maze, strategy, |_| 0.0)
+    }
+
+    /// Records every step of the search from here on as a `SolveEvent`, for `SearchSteps`.
+    fn record_events(mut self) -> Self {
+        let mut events = VecDeque::new();
+        // The start was discovered before there was anywhere to record it
+        events.extend(self.cells.first().cloned().map(SolveEvent::Discovered));
+        self.events = Some(events);
+        self
+    }
+
+    /// Records an event, made from the cells found so far, if events are being recorded.
+    fn record(&mut self, event: impl FnOnce(&[M::Cell]) -> SolveEvent<M::Cell>) {
+        if let Some(events) = &mut self.events {
+            events.push_back(event(&self.cells));
+        }
+    }
+
+    /// Returns the cells waiting in the frontier, in the order they will be taken from it.
+    fn frontier_cells(&self) -> Vec<M::Cell> {
+        let ids: Vec<usize> = match &self.frontier {
+            Frontier::Stack(stack) => stack.iter().rev().copied().collect(),
+            Frontier::Queue(queue) => queue.iter().copied().collect(),
+            Frontier::Heap(heap) => {
+                let mut entries: Vec<_> = heap.iter().map(|Reverse(entry)| *entry).collect();
+                entries.sort_unstable();
+                entries.into_iter().map(|(_, id)| id).collect()
+            },
+        };
+        let mut listed = vec![false; self.cells.len()];
+        ids.into_iter()
+            .filter(|&id| !self.closed[id] && !std::mem::replace(&mut listed[id], true))
+            .map(|id| self.cells[id].clone())
+            .collect()
+    }
+
+    /// Returns the position of a cell's entry in `Ids::Dense`.
+    fn dense_index(maze: &M, cell: &M::Cell) -> usize {
+        maze.cell_index(cell).expect("a maze with a cell count numbers every cell")
+    }
+
+    /// Returns the number given to a cell, if it has been found.
+    fn id(&self, cell: &M::Cell) -> Option<usize> {
+        match &self.ids {
+            Ids::Hashed(ids) => ids.get(cell).copied(),
+            Ids::Dense(ids) => Some(ids[Self::dense_index(self.maze, cell)]).filter(|&id| id != usize::MAX),
+        }
+    }
+
+    /// Adds a cell to the frontier, reached from the cell numbered `parent`, if it has not been
+    /// seen before or, for strategies that reopen cells, if this is a shorter route to it.
+    fn discover(&mut self, cell: M::Cell, parent: Option<usize>) {
+        let known = self.id(&cell);
+        self.discover_known(cell, known, parent);
+    }
+
+    /// Does the work of `discover`, given the number of the cell if it has been found before.
+    fn discover_known(&mut self, cell: M::Cell, known: Option<usize>, parent: Option<usize>) {
+        let distance = parent.map_or(0, |parent| self.distance[parent] + 1);
+        let id = match known {
+            None => {
+                let id = self.cells.len();
+                match &mut self.ids {
+                    Ids::Hashed(ids) => {
+                        ids.insert(cell.clone(), id);
+                    },
+                    Ids::Dense(ids) => ids[Self::dense_index(self.maze, &cell)] = id,
+                }
+                self.cells.push(cell);
+                self.parent.push(parent);
+                self.distance.push(distance);
+                self.closed.push(false);
+                self.open += 1;
+                self.stats.discovered += 1;
+                self.stats.peak_frontier = self.stats.peak_frontier.max(self.open);
+                id
+            },
+            Some(id) if self.strategy.reopens() && !self.closed[id] && distance < self.distance[id] => {
+                self.parent[id] = parent;
+                self.distance[id] = distance;
+                id
+            },
+            Some(_) => return,
+        };
+        self.push(id);
+        self.record(|cells| SolveEvent::Discovered(cells[id].clone()));
+    }
+
+    fn push(&mut self, id: usize) {
+        let order = self.pushed;
+        self.pushed += 1;
+        let distance = self.distance[id] as f64;
+        let priority = |primary, tie_break| Priority { primary, tie_break, order };
+        match &mut self.frontier {
+            Frontier::Stack(stack) => stack.push(id),
+            Frontier::Queue(queue) => queue.push_back(id),
+            Frontier::Heap(heap) => {
+                let entry = match self.strategy {
+                    Strategy::AStar => {
+                        let heuristic = (self.heuristic)(&self.cells[id]);
+                        priority(distance + heuristic, heuristic)
+                    },
+                    Strategy::Greedy => priority((self.heuristic)(&self.cells[id]), 0.0),
+                    _ => priority(distance, 0.0),
+                };
+                heap.push(Reverse((entry, id)));
+            },
+        }
+    }
+
+    /// Takes the next cell from the frontier and, unless it is the end, expands it.
+    fn step(&mut self) -> Progress<M::Cell> {
+        let Some(id) = self.frontier.pop() else {
+            self.finished = true;
+            self.record(|_| SolveEvent::Failed);
+            return Progress::Finished(None);
+        };
+        if self.closed[id] {
+            return Progress::Searching;
+        }
+        self.closed[id] = true;
+        self.open -= 1;
+        if self.cells[id] == self.end {
+            let path = self.path_to(id);
+            self.finished = true;
+            self.record(|_| SolveEvent::Found(path.clone()));
+            return Progress::Finished(Some(path));
+        }
+        self.stats.expanded += 1;
+        self.record(|cells| SolveEvent::Expanded(cells[id].clone()));
+        let (maze, cell) = (self.maze, self.cells[id].clone());
+        for adj in maze.passable_neighbours(&cell) {
+            let known = self.id(&adj);
+            if known.is_some_and(|adj_id| self.closed[adj_id]) {
+                continue;
+            }
+            self.discover_known(adj, known, Some(id));
+        }
+        Progress::Searching
+    }
+
+    /// Returns the path from the start to the cell numbered `id`.
+    fn path_to(&self, id: usize) -> Vec<M::Cell> {
+        let mut path = vec![self.cells[id].clone()];
+        let mut current = id;
+        while let Some(parent) = self.parent[current] {
+            path.push(self.cells[parent].clone());
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Runs the search to completion.
+    pub(crate) fn run(mut self) -> Solution<M::Cell> {
+        let started = Instant::now();
+        loop {
+            if let Progress::Finished(path) = self.step() {
+                self.stats.path_length = path.as_ref().map(|path| path.len() - 1);
+                self.stats.elapsed = started.elapsed();
+                return Solution { path, stats: self.stats };
+            }
+        }
+    }
+}
+
+/// The steps taken by one of the built-in solvers, in order. See `SolveEvent` for the order of the events.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::solve::{BreadthFirst, SolveEvent};
+/// use maze_solver::wall_maze::{WallMaze, InteriorPosition};
+///
+/// let start = InteriorPosition::<4, 4>::new(0, 0).unwrap();
+/// let end = InteriorPosition::<4, 4>::new(3, 3).unwrap();
+/// let maze = WallMaze::<4, 4>::new(start, end).unwrap();
+///
+/// let expanded = BreadthFirst::steps(&maze).filter(|event| matches!(event, SolveEvent::Expanded(_))).count();
+/// assert_eq!(expanded, 15);
+/// ```
+pub struct SearchSteps<'a, M: Maze + ?Sized> {
+    search: Search<'a, M>,
+    frontier_due: bool,
+}
+
+impl<'a, M: Maze + ?Sized> SearchSteps<'a, M> where M::Cell: Hash + Eq + Clone {
+    pub(crate) fn new(search: Search<'a, M>) -> Self {
+        Self { search: search.record_events(), frontier_due: false }
+    }
+}
+
+impl<M: Maze + ?Sized> Iterator for SearchSteps<'_, M> where M::Cell: Hash + Eq + Clone {
+    type Item = SolveEvent<M::Cell>;
+
+    fn next(&mut self) -> Option<SolveEvent<M::Cell>> {
+        if std::mem::take(&mut self.frontier_due) {
+            return Some(SolveEvent::Frontier(self.search.frontier_cells()));
+        }
+        loop {
+            let events = self.search.events.as_mut().unwrap();
+            if let Some(event) = events.pop_front() {
+                self.frontier_due = events.is_empty() && !self.search.finished;
+                return Some(event);
+            }
+            if self.search.finished {
+                return None;
+            }
+            self.search.step();
+        }
+    }
+}
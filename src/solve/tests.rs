@@ -0,0 +1,395 @@
+use super::*;
+use crate::MazeCell;
+use crate::block_maze::BlockMaze;
+use crate::generate::kruskal;
+use crate::rng::Xoshiro256StarStar;
+use crate::test_util::{assert_valid_path, exhaustive_shortest_steps};
+use crate::wall_maze::{DynWallMaze, InteriorPosition, WallMaze};
+
+/// A 4 × 3 grid cut in two down the middle, so that its end can never be reached.
+struct Split;
+
+impl Maze for Split {
+    type Cell = InteriorPosition<4, 3>;
+
+    fn start(&self) -> Self::Cell {
+        InteriorPosition::new(0, 0).unwrap()
+    }
+
+    fn end(&self) -> Self::Cell {
+        InteriorPosition::new(3, 2).unwrap()
+    }
+
+    fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell> {
+        let left = cell.get_x() < 2;
+        cell.adjacent_cells().filter(move |adj| (adj.get_x() < 2) == left)
+    }
+
+    fn try_solve(&self) -> Option<Vec<Self::Cell>> {
+        BreadthFirst.solve(self).path
+    }
+}
+
+/// A wall maze that hides the numbering of its cells, so that solvers hash them instead.
+struct Unnumbered<'a>(&'a WallMaze<9, 7>);
+
+impl Maze for Unnumbered<'_> {
+    type Cell = InteriorPosition<9, 7>;
+
+    fn start(&self) -> Self::Cell {
+        self.0.start()
+    }
+
+    fn end(&self) -> Self::Cell {
+        self.0.end()
+    }
+
+    fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell> {
+        self.0.passable_neighbours(cell)
+    }
+
+    fn try_solve(&self) -> Option<Vec<Self::Cell>> {
+        self.0.try_solve()
+    }
+}
+
+fn braided_mazes() -> Vec<WallMaze<9, 7>> {
+    let mut rng = Xoshiro256StarStar::new(30);
+    let start = InteriorPosition::new(0, 0).unwrap();
+    let end = InteriorPosition::new(8, 6).unwrap();
+    [0.0, 0.25, 0.5, 1.0].into_iter().flat_map(|probability| {
+        (0..5).map(|_| {
+            let mut maze = kruskal(start, end, &mut rng).unwrap();
+            maze.braid(probability, &mut rng);
+            maze
+        }).collect::<Vec<_>>()
+    }).collect()
+}
+
+#[test]
+fn test_builtin_solvers_find_paths() {
+    let registry = SolverRegistry::<WallMaze<9, 7>>::builtin();
+    for maze in braided_mazes() {
+        let shortest = maze.solve().unwrap().len();
+        for solver in registry.iter() {
+            let solution = solver.solve(&maze);
+            let path = solution.path.unwrap();
+            assert_valid_path(&maze, &path);
+            if ["breadth-first", "dijkstra", "a-star", "bidirectional"].contains(&solver.name()) {
+                assert_eq!(path.len(), shortest, "{}", solver.name());
+            } else {
+                assert!(path.len() >= shortest, "{}", solver.name());
+            }
+            assert!(solution.stats.expanded < solution.stats.discovered);
+            assert!(solution.stats.discovered <= 9 * 7);
+        }
+    }
+}
+
+#[test]
+fn test_solvers_report_unreachable_end() {
+    let registry = SolverRegistry::<Split>::builtin();
+    for solver in registry.iter().filter(|solver| solver.name() != "bidirectional") {
+        let solution = solver.solve(&Split);
+        assert_eq!(solution.path, None, "{}", solver.name());
+        // Every cell on the start's side of the split is found and expanded
+        assert_eq!(solution.stats.expanded, 6, "{}", solver.name());
+        assert_eq!(solution.stats.discovered, 6, "{}", solver.name());
+        assert_eq!(solution.stats.path_length, None, "{}", solver.name());
+    }
+
+    // The bidirectional search stops once either side of the split is used up
+    let solution = Bidirectional.solve(&Split);
+    assert_eq!(solution.path, None);
+    assert!((6..12).contains(&solution.stats.expanded));
+    assert_eq!(Split.solve(), Err(crate::MazeError::Unsolvable));
+}
+
+#[test]
+fn test_a_star_expands_fewer_cells_than_breadth_first() {
+    let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+    let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+    let maze = WallMaze::new(start, end).unwrap();
+
+    let breadth_first = BreadthFirst.solve(&maze);
+    let dijkstra = Dijkstra.solve(&maze);
+    let a_star = AStar.solve(&maze);
+    let greedy = GreedyBestFirst.solve(&maze);
+
+    // Breadth-first search and Dijkstra's algorithm visit everything closer than the end
+    assert_eq!(breadth_first.stats.expanded, 99);
+    assert_eq!(dijkstra.stats.expanded, 99);
+    // With no walls in the way, the heuristic leads straight to the end
+    assert_eq!(a_star.stats.expanded, 18);
+    assert_eq!(greedy.stats.expanded, 18);
+    for solution in [breadth_first, dijkstra, a_star, greedy] {
+        assert_eq!(solution.path.unwrap().len(), 19);
+    }
+}
+
+#[test]
+fn test_solver_stats() {
+    for maze in braided_mazes() {
+        for solver in SolverRegistry::builtin().iter() {
+            let solution = solver.solve(&maze);
+            let path = solution.path.unwrap();
+            assert_eq!(solution.stats.path_length, Some(path.len() - 1), "{}", solver.name());
+            assert!(solution.stats.peak_frontier >= 1, "{}", solver.name());
+            assert!(solution.stats.peak_frontier <= solution.stats.discovered, "{}", solver.name());
+        }
+    }
+
+    // Breadth-first search of an open grid holds a diagonal of cells at a time
+    let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+    let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+    let maze = WallMaze::new(start, end).unwrap();
+    assert_eq!(BreadthFirst.solve(&maze).stats.peak_frontier, 10);
+    // Depth-first search of a corridor holds only the cell ahead
+    let start = InteriorPosition::<10, 1>::new(0, 0).unwrap();
+    let end = InteriorPosition::<10, 1>::new(9, 0).unwrap();
+    let maze = WallMaze::new(start, end).unwrap();
+    assert_eq!(DepthFirst.solve(&maze).stats.peak_frontier, 1);
+    assert_eq!(Bidirectional.solve(&maze).stats.peak_frontier, 2);
+}
+
+#[test]
+fn test_compare() {
+    let mut rng = Xoshiro256StarStar::new(25);
+    let start = InteriorPosition::<9, 7>::new(0, 0).unwrap();
+    let end = InteriorPosition::<9, 7>::new(8, 6).unwrap();
+    let mut maze = kruskal(start, end, &mut rng).unwrap();
+    maze.braid(0.5, &mut rng);
+
+    let table = compare(&maze);
+    let registry = SolverRegistry::builtin();
+    assert_eq!(table.get_rows().iter().map(|(name, _)| *name).collect::<Vec<_>>(), registry.names().collect::<Vec<_>>());
+    for (name, stats) in table.get_rows() {
+        let expected = registry.get(name).unwrap().solve(&maze).stats;
+        assert_eq!(stats.expanded, expected.expanded, "{name}");
+        assert_eq!(stats.discovered, expected.discovered, "{name}");
+        assert_eq!(stats.peak_frontier, expected.peak_frontier, "{name}");
+        assert_eq!(stats.path_length, expected.path_length, "{name}");
+    }
+    assert!(table.get("no-such-solver").is_none());
+
+    // A heading line and a line per solver, with the columns lined up
+    let text = table.to_string();
+    let lines: Vec<_> = text.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert!(lines[0].starts_with("solver") && lines[0].ends_with("elapsed"));
+    assert!(lines.iter().all(|line| line.chars().count() == lines[0].chars().count()));
+    for ((name, stats), line) in table.get_rows().iter().zip(&lines[1..]) {
+        let columns: Vec<_> = line.split_whitespace().collect();
+        assert_eq!(columns[0], *name);
+        assert_eq!(columns[1], stats.path_length.unwrap().to_string());
+        assert_eq!(columns[2], stats.expanded.to_string());
+        assert_eq!(columns[3], stats.discovered.to_string());
+        assert_eq!(columns[4], stats.peak_frontier.to_string());
+    }
+
+    // The end cannot be reached, so there are no path lengths to show
+    let table = SolverRegistry::builtin().compare(&Split);
+    assert!(table.to_string().lines().skip(1).all(|line| line.split_whitespace().nth(1) == Some("-")));
+    assert_eq!(ComparisonTable::default().to_string().lines().count(), 1);
+}
+
+#[test]
+fn test_numbered_cells_search_as_hashed_cells() {
+    let numbered = SolverRegistry::<WallMaze<9, 7>>::builtin();
+    for maze in braided_mazes() {
+        assert_eq!(maze.cell_count(), Some(63));
+        let hashed = SolverRegistry::<Unnumbered>::builtin();
+        for name in numbered.names() {
+            let expected = numbered.get(name).unwrap().solve(&maze);
+            let solution = hashed.get(name).unwrap().solve(&Unnumbered(&maze));
+            assert_eq!(solution.path, expected.path, "{name}");
+            assert_eq!(SolverStats { elapsed: expected.stats.elapsed, ..solution.stats }, expected.stats, "{name}");
+        }
+        assert!(AStar::steps(&Unnumbered(&maze)).eq(AStar::steps(&maze)));
+    }
+}
+
+#[test]
+fn test_solvers_on_other_mazes() {
+    let start = InteriorPosition::<5, 5>::new(0, 0).unwrap();
+    let end = InteriorPosition::<5, 5>::new(4, 0).unwrap();
+    let mut block_maze = BlockMaze::new(start, end).unwrap();
+    for y in 0..4 {
+        block_maze.add_block(InteriorPosition::new(2, y).unwrap()).unwrap();
+    }
+    let maze = kruskal(start, end, &mut Xoshiro256StarStar::new(31)).unwrap();
+    let dyn_maze = DynWallMaze::from(maze.clone());
+
+    let shortest = maze.solve().unwrap().len();
+    let block_solvers = SolverRegistry::<BlockMaze<5, 5>>::builtin();
+    let dyn_solvers = SolverRegistry::<DynWallMaze>::builtin();
+    for name in block_solvers.names() {
+        let path = block_solvers.get(name).unwrap().solve(&block_maze).path.unwrap();
+        assert_valid_path(&block_maze, &path);
+
+        let dyn_path = dyn_solvers.get(name).unwrap().solve(&dyn_maze).path.unwrap();
+        assert_valid_path(&dyn_maze, &dyn_path);
+        // A perfect maze has only one path
+        assert_eq!(dyn_path.len(), shortest);
+    }
+    assert_eq!(BreadthFirst.solve(&block_maze).path.unwrap().len(), 13);
+}
+
+/// A solver that defers to the maze's own `solve`, counting no work.
+struct Inherent;
+
+impl<const WIDTH: usize, const HEIGHT: usize> Solver<WallMaze<WIDTH, HEIGHT>> for Inherent {
+    fn name(&self) -> &'static str {
+        "inherent"
+    }
+
+    fn solve(&self, maze: &WallMaze<WIDTH, HEIGHT>) -> Solution<InteriorPosition<WIDTH, HEIGHT>> {
+        Solution { path: maze.solve().ok(), stats: SolverStats::default() }
+    }
+}
+
+/// A solver that claims a built-in solver's name.
+struct Impostor;
+
+impl<M: Maze + ?Sized> Solver<M> for Impostor {
+    fn name(&self) -> &'static str {
+        "a-star"
+    }
+
+    fn solve(&self, _maze: &M) -> Solution<M::Cell> {
+        Solution { path: None, stats: SolverStats::default() }
+    }
+}
+
+#[test]
+fn test_registry() {
+    let mut registry = SolverRegistry::<WallMaze<4, 4>>::builtin();
+    assert_eq!(registry.names().collect::<Vec<_>>(), ["depth-first", "breadth-first", "dijkstra", "a-star", "greedy-best-first", "bidirectional"]);
+    assert!(registry.get("bfs").is_none());
+
+    assert!(registry.register(Inherent).is_none());
+    let replaced = registry.register(Impostor).unwrap();
+    assert_eq!(replaced.name(), "a-star");
+    assert_eq!(registry.names().count(), 7);
+
+    let start = InteriorPosition::new(0, 0).unwrap();
+    let end = InteriorPosition::new(3, 3).unwrap();
+    let maze = WallMaze::new(start, end).unwrap();
+    assert_eq!(registry.get("inherent").unwrap().solve(&maze).path, Some(maze.solve().unwrap()));
+    assert_eq!(registry.get("a-star").unwrap().solve(&maze).path, None);
+
+    assert_eq!(SolverRegistry::<WallMaze<4, 4>>::default().names().count(), 0);
+}
+
+#[test]
+fn test_bidirectional_finds_shortest_paths() {
+    use crate::rng::MazeRng;
+
+    let mut rng = Xoshiro256StarStar::new(32);
+    for _ in 0..100 {
+        let start = InteriorPosition::<4, 4>::new(rng.below(4), rng.below(4)).unwrap();
+        let end = InteriorPosition::<4, 4>::new(rng.below(4), rng.below(4)).unwrap();
+        if start == end {
+            continue;
+        }
+
+        let mut wall_maze = WallMaze::new(start, end).unwrap();
+        let mut block_maze = BlockMaze::new(start, end).unwrap();
+        for _ in 0..rng.below(16) {
+            let x = rng.below(4);
+            let y = rng.below(4);
+            let _ = block_maze.add_block(InteriorPosition::new(x, y).unwrap());
+            let orientation = if rng.chance(0.5) { crate::wall_maze::Orientation::Vertical } else { crate::wall_maze::Orientation::Horizontal };
+            if let Ok(wall) = crate::wall_maze::InteriorWall::new(x, y, orientation) {
+                let _ = wall_maze.add_interior_wall(wall);
+            }
+        }
+
+        let path = Bidirectional.solve(&wall_maze).path.unwrap();
+        assert_valid_path(&wall_maze, &path);
+        assert_eq!(Some(path.len() - 1), exhaustive_shortest_steps(&wall_maze));
+
+        let path = Bidirectional.solve(&block_maze).path.unwrap();
+        assert_valid_path(&block_maze, &path);
+        assert_eq!(Some(path.len() - 1), exhaustive_shortest_steps(&block_maze));
+    }
+}
+
+#[test]
+fn test_bidirectional_expands_fewer_cells_in_open_mazes() {
+    let start = InteriorPosition::<100, 100>::new(30, 50).unwrap();
+    let end = InteriorPosition::<100, 100>::new(70, 50).unwrap();
+    let maze = WallMaze::new(start, end).unwrap();
+
+    let bidirectional = Bidirectional.solve(&maze);
+    let breadth_first = BreadthFirst.solve(&maze);
+    assert_eq!(bidirectional.path.unwrap().len(), 41);
+    assert_eq!(breadth_first.path.unwrap().len(), 41);
+    assert!(bidirectional.stats.expanded * 2 < breadth_first.stats.expanded);
+
+    // Next to each other, the two searches meet straight away
+    let neighbour = InteriorPosition::new(31, 50).unwrap();
+    let maze = WallMaze::new(start, neighbour).unwrap();
+    let solution = Bidirectional.solve(&maze);
+    assert_eq!(solution.path, Some(vec![start, neighbour]));
+    assert_eq!(solution.stats.expanded, 1);
+}
+
+/// Returns the events of a search, checking that each cell taken from the frontier was the first of it.
+fn checked_steps<C: Clone + PartialEq + std::fmt::Debug>(steps: impl Iterator<Item = SolveEvent<C>>) -> Vec<SolveEvent<C>> {
+    let events: Vec<_> = steps.collect();
+    for pair in events.windows(2) {
+        if let [SolveEvent::Frontier(frontier), next] = pair {
+            match next {
+                SolveEvent::Expanded(cell) => assert_eq!(frontier.first(), Some(cell)),
+                SolveEvent::Found(path) => assert_eq!(frontier.first(), path.last()),
+                SolveEvent::Failed => assert!(frontier.is_empty()),
+                other => panic!("{other:?} after the frontier"),
+            }
+        }
+    }
+    events
+}
+
+fn count_events<C>(events: &[SolveEvent<C>], wanted: fn(&SolveEvent<C>) -> bool) -> usize {
+    events.iter().filter(|event| wanted(event)).count()
+}
+
+#[test]
+fn test_search_steps_match_solve() {
+    for maze in braided_mazes() {
+        let solutions = [
+            (checked_steps(DepthFirst::steps(&maze)), DepthFirst.solve(&maze)),
+            (checked_steps(BreadthFirst::steps(&maze)), BreadthFirst.solve(&maze)),
+            (checked_steps(Dijkstra::steps(&maze)), Dijkstra.solve(&maze)),
+            (checked_steps(AStar::steps(&maze)), AStar.solve(&maze)),
+            (checked_steps(GreedyBestFirst::steps(&maze)), GreedyBestFirst.solve(&maze)),
+        ];
+        for (events, solution) in solutions {
+            assert_eq!(events.first(), Some(&SolveEvent::Discovered(maze.get_start())));
+            assert_eq!(events.last(), Some(&SolveEvent::Found(solution.path.unwrap())));
+            assert_eq!(count_events(&events, |event| matches!(event, SolveEvent::Expanded(_))), solution.stats.expanded);
+            assert!(count_events(&events, |event| matches!(event, SolveEvent::Discovered(_))) >= solution.stats.discovered);
+        }
+    }
+}
+
+#[test]
+fn test_search_steps_on_unreachable_end() {
+    let events = checked_steps(BreadthFirst::steps(&Split));
+    assert_eq!(events.last(), Some(&SolveEvent::Failed));
+    assert_eq!(events[events.len() - 2], SolveEvent::Frontier(Vec::new()));
+    assert_eq!(count_events(&events, |event| matches!(event, SolveEvent::Expanded(_))), 6);
+
+    // Depth-first search takes the most recently discovered cell first
+    let events = checked_steps(DepthFirst::steps(&Split));
+    let pos = |x, y| InteriorPosition::<4, 3>::new(x, y).unwrap();
+    assert_eq!(events[..6], [
+        SolveEvent::Discovered(pos(0, 0)),
+        SolveEvent::Frontier(vec![pos(0, 0)]),
+        SolveEvent::Expanded(pos(0, 0)),
+        SolveEvent::Discovered(pos(1, 0)),
+        SolveEvent::Discovered(pos(0, 1)),
+        SolveEvent::Frontier(vec![pos(0, 1), pos(1, 0)]),
+    ]);
+}
@@ -0,0 +1,35 @@
+//! Checks shared by the tests of every kind of maze.
+
+use crate::{are_passable_neighbours, Maze};
+
+/// Asserts that a path runs from the start of the maze to its end, taking only single steps
+/// between passable neighbours.
+pub(crate) fn assert_valid_path<M: Maze>(maze: &M, path: &[M::Cell]) where M::Cell: PartialEq + std::fmt::Debug {
+    assert_eq!(path.first(), Some(&maze.start()));
+    assert_eq!(path.last(), Some(&maze.end()));
+    for step in path.windows(2) {
+        assert!(are_passable_neighbours(maze, &step[0], &step[1]), "{:?} to {:?}", step[0], step[1]);
+    }
+}
+
+/// Returns the number of steps in a shortest path through the maze, by trying every path that
+/// never visits a cell twice.
+pub(crate) fn exhaustive_shortest_steps<M: Maze>(maze: &M) -> Option<usize> where M::Cell: PartialEq {
+    fn extend<M: Maze>(maze: &M, path: &mut Vec<M::Cell>, best: &mut Option<usize>) where M::Cell: PartialEq {
+        if *path.last().unwrap() == maze.end() {
+            *best = Some(best.map_or(path.len() - 1, |steps| steps.min(path.len() - 1)));
+            return;
+        }
+        let neighbours: Vec<_> = maze.passable_neighbours(path.last().unwrap()).collect();
+        for adj in neighbours {
+            if !path.contains(&adj) {
+                path.push(adj);
+                extend(maze, path, best);
+                path.pop();
+            }
+        }
+    }
+    let mut best = None;
+    extend(maze, &mut vec![maze.start()], &mut best);
+    best
+}
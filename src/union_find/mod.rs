@@ -0,0 +1,114 @@
+//! A disjoint-set forest for tracking which elements have been joined together.
+
+#[cfg(test)]
+mod tests;
+
+/// A partition of the elements `0..len` into disjoint sets, which can be merged.
+///
+/// Sets are stored as trees of parent pointers, merged by size and flattened by path halving
+/// whenever they are searched, so any sequence of operations runs in close to constant
+/// amortised time per operation.
+///
+/// # Examples
+///
+/// ```
+/// use maze_solver::union_find::UnionFind;
+///
+/// let mut sets = UnionFind::new(4);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(2, 3));
+/// assert!(!sets.connected(1, 2));
+///
+/// assert!(sets.union(1, 2));
+/// assert!(sets.connected(0, 3));
+/// assert_eq!(sets.set_count(), 1);
+///
+/// // Elements that are already in the same set are not merged again
+/// assert!(!sets.union(3, 0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    set_count: usize,
+}
+
+impl UnionFind {
+    /// Creates a partition of `0..len` in which every element is in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            set_count: len,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Returns the representative of the set containing `element`.
+    ///
+    /// Two elements are in the same set exactly when they have the same representative.
+    /// The representative of a set may change when it is merged with another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is not less than `len()`.
+    pub fn find(&mut self, mut element: usize) -> usize {
+        while self.parent[element] != element {
+            self.parent[element] = self.parent[self.parent[element]];
+            element = self.parent[element];
+        }
+        element
+    }
+
+    /// Merges the sets containing `a` and `b`, returning `false` if they were already the same set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either element is not less than `len()`.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.set_count -= 1;
+        true
+    }
+
+    /// Returns `true` if `a` and `b` are in the same set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either element is not less than `len()`.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the number of elements in the set containing `element`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is not less than `len()`.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+}
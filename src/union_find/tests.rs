@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_new_sets_are_singletons() {
+    let mut sets = UnionFind::new(5);
+    assert_eq!(sets.len(), 5);
+    assert_eq!(sets.set_count(), 5);
+    for i in 0..5 {
+        assert_eq!(sets.find(i), i);
+        assert_eq!(sets.set_size(i), 1);
+    }
+    assert!(UnionFind::new(0).is_empty());
+}
+
+#[test]
+fn test_union_matches_naive_partition() {
+    // Compares against a labelling that relabels a whole set on every merge
+    const LEN: usize = 40;
+    let mut sets = UnionFind::new(LEN);
+    let mut labels: Vec<usize> = (0..LEN).collect();
+    let mut state: u64 = 0x2545f4914f6cdd1d;
+    for _ in 0..60 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let (a, b) = ((state % LEN as u64) as usize, ((state >> 32) % LEN as u64) as usize);
+
+        let expected = labels[a] != labels[b];
+        assert_eq!(sets.union(a, b), expected);
+        let (from, to) = (labels[b], labels[a]);
+        for label in labels.iter_mut().filter(|label| **label == from) {
+            *label = to;
+        }
+
+        let mut distinct = labels.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(sets.set_count(), distinct.len());
+        for i in 0..LEN {
+            assert_eq!(sets.connected(a, i), labels[a] == labels[i]);
+        }
+        assert_eq!(sets.set_size(a), labels.iter().filter(|&&label| label == labels[a]).count());
+    }
+}
+
+#[test]
+fn test_long_chain() {
+    let mut sets = UnionFind::new(100_000);
+    for i in 1..100_000 {
+        assert!(sets.union(i - 1, i));
+    }
+    assert_eq!(sets.set_count(), 1);
+    assert!(sets.connected(0, 99_999));
+    assert_eq!(sets.set_size(50_000), 100_000);
+}
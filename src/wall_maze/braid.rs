@@ -0,0 +1,75 @@
+//! Adding and removing loops, shared by the const-generic and runtime-sized wall mazes.
+
+use crate::rng::MazeRng;
+use crate::union_find::UnionFind;
+
+use super::{Orientation, Wall};
+use super::wall_set::{interior_walls, WallSet};
+
+/// Returns the cells of a `width` × `height` grid next to `(x, y)`.
+fn neighbours(width: usize, height: usize, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    [
+        (x > 0).then(|| (x - 1, y)),
+        (x + 1 < width).then_some((x + 1, y)),
+        (y > 0).then(|| (x, y - 1)),
+        (y + 1 < height).then_some((x, y + 1)),
+    ].into_iter().flatten()
+}
+
+/// Returns the number of cells reachable from `cell` in a single step.
+fn open_count(walls: &WallSet, width: usize, height: usize, cell: (usize, usize)) -> usize {
+    neighbours(width, height, cell).filter(|&adj| !walls.separates(cell, adj)).count()
+}
+
+/// Removes a wall from each dead end of the grid with the given probability, returning the
+/// number of walls removed.
+///
+/// Dead ends are visited in row-major order. Each one that is still a dead end when it is
+/// reached opens up to a neighbour that is a dead end too if there is one, which removes two
+/// dead ends with a single wall, and otherwise to a random walled-off neighbour.
+pub(crate) fn braid(walls: &mut WallSet, width: usize, height: usize, probability: f64, rng: &mut impl MazeRng) -> usize {
+    let mut removed = 0;
+    for cell in (0..height).flat_map(|y| (0..width).map(move |x| (x, y))) {
+        if open_count(walls, width, height, cell) != 1 || !rng.chance(probability) {
+            continue;
+        }
+        let walled: Vec<_> = neighbours(width, height, cell).filter(|&adj| walls.separates(cell, adj)).collect();
+        let dead_ends: Vec<_> = walled.iter().copied().filter(|&adj| open_count(walls, width, height, adj) == 1).collect();
+        let candidates = if dead_ends.is_empty() { &walled } else { &dead_ends };
+        if let Some(&adj) = rng.choose(candidates) {
+            walls.remove(wall_between(cell, adj));
+            removed += 1;
+        }
+    }
+    removed
+}
+
+/// Adds walls across passages until the grid has no loops left, returning the number of walls added.
+///
+/// The passages are considered in a random order, and each is kept only if it joins two cells
+/// that the passages kept so far do not already connect. Every pair of cells connected before
+/// remains connected.
+pub(crate) fn unbraid(walls: &mut WallSet, width: usize, height: usize, rng: &mut impl MazeRng) -> usize {
+    let mut passages: Vec<Wall> = interior_walls(width, height).filter(|&wall| !walls.contains(wall)).collect();
+    rng.shuffle(&mut passages);
+    let mut sets = UnionFind::new(width * height);
+    let mut added = 0;
+    for wall in passages {
+        let (x, y) = (wall.x, wall.y);
+        let other = match wall.orientation {
+            Orientation::Horizontal => (x, y + 1),
+            Orientation::Vertical => (x + 1, y),
+        };
+        if !sets.union(y * width + x, other.1 * width + other.0) {
+            walls.insert(wall);
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Returns the wall between two adjacent cells.
+fn wall_between((x1, y1): (usize, usize), (x2, y2): (usize, usize)) -> Wall {
+    let orientation = if y1 == y2 { Orientation::Vertical } else { Orientation::Horizontal };
+    Wall { x: x1.min(x2), y: y1.min(y2), orientation }
+}
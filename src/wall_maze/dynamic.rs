@@ -0,0 +1,805 @@
+//! A wall maze whose dimensions are chosen at runtime.
+//!
+//! `DynWallMaze`, `DynPosition` and `DynWall` mirror `WallMaze<WIDTH, HEIGHT>`,
+//! `InteriorPosition<WIDTH, HEIGHT>` and `InteriorWall<WIDTH, HEIGHT>`, using the same
+//! coordinate system, wall representation and solvability guarantee. Each position and
+//! wall carries the dimensions of the maze it belongs to, and operations combining values
+//! of different dimensions are rejected.
+//!
+//! Values convert into their const-generic counterparts with `TryFrom`, which fails when the
+//! runtime dimensions do not match the const parameters, and back again with `From`.
+
+use crate::{Maze, MazeCell, MazeError, MazeWall, MutSolubleMaze, MutSolubleWallMaze, PathHeuristic};
+use crate::WallMaze as WallMazeTrait;
+use crate::rng::MazeRng;
+use crate::solve::{AStar, SearchSteps, Solution, Solver};
+use std::hash::{Hash, Hasher};
+
+use super::{Direction, InteriorPosition, InteriorWall, Orientation, Wall, WallMaze};
+use super::Direction::{Up, Down, Left, Right};
+use super::Orientation::{Horizontal, Vertical};
+use super::search::KnownPath;
+use super::wall_set::WallSet;
+
+#[cfg(test)]
+mod tests;
+
+/// A wall that is guaranteed to be within the interior bounds of a maze of runtime dimensions.
+///
+/// This is the runtime-sized counterpart of `InteriorWall<WIDTH, HEIGHT>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynWall {
+    wall: Wall,
+    width: usize,
+    height: usize,
+}
+
+impl DynWall {
+    /// Creates a new `DynWall` at the specified position and orientation for a maze of the given dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - x >= width or y >= height
+    /// - x = width - 1 and orientation is Vertical
+    /// - y = height - 1 and orientation is Horizontal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{DynWall, Orientation};
+    ///
+    /// assert!(DynWall::new(2, 3, Orientation::Horizontal, 5, 5).is_ok());
+    /// assert!(DynWall::new(4, 2, Orientation::Vertical, 5, 5).is_err());
+    /// ```
+    pub fn new(x: usize, y: usize, orientation: Orientation, width: usize, height: usize) -> Result<Self, MazeError> {
+        let wall = Wall { x, y, orientation };
+        if x >= width || y >= height || (x == width - 1 && orientation == Vertical) || (y == height - 1 && orientation == Horizontal) {
+            Err(MazeError::WallOutOfBounds { wall, width, height })
+        } else {
+            Ok(Self { wall, width, height })
+        }
+    }
+
+    /// Creates a `DynWall` from an existing `Wall` structure for a maze of the given dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wall would be invalid for these dimensions.
+    pub fn from_wall(wall: Wall, width: usize, height: usize) -> Result<Self, MazeError> {
+        Self::new(wall.x, wall.y, wall.orientation, width, height)
+    }
+
+    /// Creates a `DynWall` at the given position with the specified orientation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting wall would lie on the exterior boundary of the maze.
+    pub fn from_position_and_orientation(pos: DynPosition, orientation: Orientation) -> Result<Self, MazeError> {
+        Self::new(pos.x, pos.y, orientation, pos.width, pos.height)
+    }
+
+    /// Returns the x-coordinate of this wall.
+    pub fn get_x(self) -> usize {
+        self.wall.x
+    }
+
+    /// Returns the y-coordinate of this wall.
+    pub fn get_y(self) -> usize {
+        self.wall.y
+    }
+
+    /// Returns the orientation of this wall.
+    pub fn get_orientation(self) -> Orientation {
+        self.wall.orientation
+    }
+
+    /// Returns the underlying `Wall` structure.
+    pub fn get_wall(self) -> Wall {
+        self.wall
+    }
+
+    /// Returns the width of the maze this wall belongs to.
+    pub fn get_width(self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the maze this wall belongs to.
+    pub fn get_height(self) -> usize {
+        self.height
+    }
+
+    /// Iterates over every possible interior wall of a maze of the given dimensions,
+    /// in the same order as `InteriorWall::all`.
+    pub fn all(width: usize, height: usize) -> impl Iterator<Item = Self> {
+        super::wall_set::interior_walls(width, height).map(move |wall| Self { wall, width, height })
+    }
+}
+
+/// A position guaranteed to be within the bounds of a maze of runtime dimensions.
+///
+/// This is the runtime-sized counterpart of `InteriorPosition<WIDTH, HEIGHT>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynPosition {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl DynPosition {
+    /// Creates a new `DynPosition` at the specified coordinates for a maze of the given dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if x >= width or y >= height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::DynPosition;
+    ///
+    /// assert!(DynPosition::new(2, 3, 5, 5).is_ok());
+    /// assert!(DynPosition::new(5, 3, 5, 5).is_err());
+    /// ```
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Result<Self, MazeError> {
+        if x >= width || y >= height {
+            Err(MazeError::PositionOutOfBounds { x, y, width, height })
+        } else {
+            Ok(Self { x, y, width, height })
+        }
+    }
+
+    /// Returns the x-coordinate of this position.
+    pub fn get_x(self) -> usize {
+        self.x
+    }
+
+    /// Returns the y-coordinate of this position.
+    pub fn get_y(self) -> usize {
+        self.y
+    }
+
+    /// Returns the width of the maze this position belongs to.
+    pub fn get_width(self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the maze this position belongs to.
+    pub fn get_height(self) -> usize {
+        self.height
+    }
+
+    /// Returns a `Vec<DynPosition>` of all positions adjacent to this position within the maze boundaries.
+    pub fn adjacent_positions(self) -> Vec<Self> {
+        let mut positions = Vec::new();
+        if self.x > 0 {
+            positions.push(Self { x: self.x - 1, ..self });
+        }
+        if self.x < self.width - 1 {
+            positions.push(Self { x: self.x + 1, ..self });
+        }
+        if self.y > 0 {
+            positions.push(Self { y: self.y - 1, ..self });
+        }
+        if self.y < self.height - 1 {
+            positions.push(Self { y: self.y + 1, ..self });
+        }
+        positions
+    }
+
+    /// Returns the Manhattan distance between this position and another position.
+    pub fn min_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Returns `true` if the other position is adjacent to this position ignoring walls.
+    pub fn adjacent_to(self, other: Self) -> bool {
+        self.adjacent_positions().contains(&other)
+    }
+
+    /// Determines if this position is separated from another position by a wall in a given `DynWallMaze`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Either position belongs to a maze of different dimensions
+    /// - The positions are not adjacent
+    /// - The positions are the same
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{DynPosition, DynWallMaze, Wall, Orientation};
+    ///
+    /// let start = DynPosition::new(0, 0, 3, 3).unwrap();
+    /// let end = DynPosition::new(2, 2, 3, 3).unwrap();
+    /// let mut maze = DynWallMaze::new(start, end).unwrap();
+    /// maze.add_wall(Wall::new(1, 1, Orientation::Horizontal)).unwrap();
+    ///
+    /// let pos1 = DynPosition::new(1, 1, 3, 3).unwrap();
+    /// let pos2 = DynPosition::new(1, 2, 3, 3).unwrap();
+    /// assert!(pos1.separated_by_wall(pos2, &maze).unwrap());
+    /// assert!(pos1.separated_by_wall(start, &maze).is_err());
+    /// ```
+    pub fn separated_by_wall(self, other: Self, maze: &DynWallMaze) -> Result<bool, MazeError> {
+        maze.check_position(self)?;
+        maze.check_position(other)?;
+        if self == other {
+            Err(MazeError::SamePosition { x: self.x, y: self.y })
+        } else if !self.adjacent_to(other) {
+            Err(MazeError::NotAdjacent { first: (self.x, self.y), second: (other.x, other.y) })
+        } else {
+            let proposed_wall = match (self.x.cmp(&other.x), self.y.cmp(&other.y)) {
+                (std::cmp::Ordering::Less, _) => DynWall::from_position_and_orientation(self, Vertical)?,
+                (std::cmp::Ordering::Greater, _) => DynWall::from_position_and_orientation(other, Vertical)?,
+                (_, std::cmp::Ordering::Less) => DynWall::from_position_and_orientation(self, Horizontal)?,
+                _ => DynWall::from_position_and_orientation(other, Horizontal)?,
+            };
+            Ok(maze.walls.contains(proposed_wall.wall))
+        }
+    }
+
+    /// Creates a new `DynPosition` by shifting this position in the specified direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if moving in the specified direction would cause the position
+    /// to leave the maze boundaries.
+    pub fn shifted_by(self, direction: Direction) -> Result<Self, MazeError> {
+        let out_of_bounds = MazeError::MoveOutOfBounds { x: self.x, y: self.y, direction, width: self.width, height: self.height };
+        match direction {
+            Up => {
+                if self.y == 0 {
+                    Err(out_of_bounds)
+                } else {
+                    Ok(Self { y: self.y - 1, ..self })
+                }
+            },
+            Down => Self::new(self.x, self.y + 1, self.width, self.height).map_err(|_| out_of_bounds),
+            Left => {
+                if self.x == 0 {
+                    Err(out_of_bounds)
+                } else {
+                    Ok(Self { x: self.x - 1, ..self })
+                }
+            },
+            Right => Self::new(self.x + 1, self.y, self.width, self.height).map_err(|_| out_of_bounds),
+        }
+    }
+
+    /// Mutates this position by moving it in the specified direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if moving in the specified direction would cause the position
+    /// to leave the maze boundaries. In case of error, the position remains unchanged.
+    pub fn move_by(&mut self, direction: Direction) -> Result<(), MazeError> {
+        let new_pos = self.shifted_by(direction)?;
+        *self = new_pos;
+        Ok(())
+    }
+}
+
+/// A maze with infinitely thin walls placed between grid cells, with dimensions chosen at runtime.
+///
+/// This is the runtime-sized counterpart of `WallMaze<WIDTH, HEIGHT>` and offers the same
+/// guarantee that there is always a path from the start to the end position, kept cheap
+/// in the same way by remembering one path from start to end.
+#[derive(Debug, Clone)]
+pub struct DynWallMaze {
+    width: usize,
+    height: usize,
+    start: DynPosition,
+    end: DynPosition,
+    walls: WallSet,
+    known_path: KnownPath<DynPosition>,
+}
+
+impl PartialEq for DynWallMaze {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.walls == other.walls
+    }
+}
+
+impl Eq for DynWallMaze {}
+
+impl Hash for DynWallMaze {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.walls.hash(state);
+    }
+}
+
+impl DynWallMaze {
+    /// Creates a new empty `DynWallMaze` with the specified start and end positions.
+    ///
+    /// The dimensions of the maze are taken from the start position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The start and end positions belong to mazes of different dimensions
+    /// - The start and end positions are the same
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{DynWallMaze, DynPosition};
+    ///
+    /// let start = DynPosition::new(0, 0, 7, 3).unwrap();
+    /// let end = DynPosition::new(6, 2, 7, 3).unwrap();
+    /// let maze = DynWallMaze::new(start, end).unwrap();
+    /// assert_eq!((maze.get_width(), maze.get_height()), (7, 3));
+    ///
+    /// assert!(DynWallMaze::new(start, start).is_err());
+    /// ```
+    pub fn new(start: DynPosition, end: DynPosition) -> Result<Self, MazeError> {
+        Self::from_walls(start, end, Vec::new())
+    }
+
+    /// Creates a new `DynWallMaze` with the specified start, end, and walls.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The start, end and walls do not all share the same dimensions
+    /// - The start and end positions are the same
+    /// - The maze is not solvable with the given walls
+    pub fn from_walls(start: DynPosition, end: DynPosition, walls: Vec<DynWall>) -> Result<Self, MazeError> {
+        let (width, height) = (start.width, start.height);
+        if (end.width, end.height) != (width, height) {
+            return Err(MazeError::DimensionMismatch { expected: (width, height), found: (end.width, end.height) });
+        }
+        if let Some(wall) = walls.iter().find(|w| (w.width, w.height) != (width, height)) {
+            return Err(MazeError::DimensionMismatch { expected: (width, height), found: (wall.width, wall.height) });
+        }
+        if start == end {
+            return Err(MazeError::StartIsEnd { x: start.x, y: start.y });
+        }
+        let mut wall_set = WallSet::new(width, height);
+        for wall in walls {
+            wall_set.insert(wall.wall);
+        }
+        let mut maze = Self { width, height, start, end, walls: wall_set, known_path: KnownPath::unknown() };
+        if !maze.refresh_known_path() {
+            Err(MazeError::Unsolvable)
+        } else {
+            Ok(maze)
+        }
+    }
+
+    /// Returns the width of the maze.
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the maze.
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the start position of the maze.
+    pub fn get_start(&self) -> DynPosition {
+        self.start
+    }
+
+    /// Returns the end position of the maze.
+    pub fn get_end(&self) -> DynPosition {
+        self.end
+    }
+
+    /// Returns `true` if the wall is in the maze. A wall belonging to a maze of different
+    /// dimensions is never in this one.
+    pub fn has_wall(&self, wall: DynWall) -> bool {
+        (wall.width, wall.height) == (self.width, self.height) && self.walls.contains(wall.wall)
+    }
+
+    /// Iterates over the walls in the maze, in the order of `DynWall::all`.
+    pub fn walls(&self) -> impl Iterator<Item = DynWall> {
+        self.walls.iter().map(|wall| DynWall { wall, width: self.width, height: self.height })
+    }
+
+    /// Iterates over the open passages of the maze, as with `WallMaze::passages`.
+    pub fn passages(&self) -> impl Iterator<Item = [DynPosition; 2]> {
+        DynWall::all(self.width, self.height)
+            .filter(|&wall| !self.walls.contains(wall.wall))
+            .map(|wall| wall.surrounding_cells())
+    }
+
+    /// Returns the positions adjacent to `pos` that are not separated from it by a wall.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position belongs to a maze of different dimensions.
+    pub fn open_neighbours(&self, pos: DynPosition) -> Result<Vec<DynPosition>, MazeError> {
+        self.check_position(pos)?;
+        Ok(self.walls.open_neighbours((pos.x, pos.y), self.height).map(|(x, y)| DynPosition { x, y, ..pos }).collect())
+    }
+
+    /// Finds a path from the start to the end position in the maze.
+    ///
+    /// Returns a vector of positions representing the path, including both start and end positions.
+    /// As with `WallMaze::solve`, the path is a shortest one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no path exists from start to end.
+    pub fn solve(&self) -> Result<Vec<DynPosition>, MazeError> {
+        AStar.solve(self).path.ok_or(MazeError::Unsolvable)
+    }
+
+    /// Finds a path from the start to the end as `solve` does, and measures the work done finding
+    /// it, as with `WallMaze::solve_with_stats`.
+    pub fn solve_with_stats(&self) -> Solution<DynPosition> {
+        AStar.solve(self)
+    }
+
+    /// Returns the steps `solve` takes to find its path, one at a time, as with `WallMaze::solve_steps`.
+    pub fn solve_steps(&self) -> SearchSteps<'_, Self> {
+        AStar::steps(self)
+    }
+
+    /// Searches for a new path from start to end and remembers it, returning whether one was found.
+    fn refresh_known_path(&mut self) -> bool {
+        match self.solve() {
+            Ok(path) => {
+                self.known_path = KnownPath::new(&path);
+                true
+            },
+            Err(_) => {
+                self.known_path = KnownPath::unknown();
+                false
+            }
+        }
+    }
+
+    /// Checks that a position belongs to a maze with the same dimensions as this one.
+    fn check_position(&self, pos: DynPosition) -> Result<(), MazeError> {
+        if (pos.width, pos.height) != (self.width, self.height) {
+            Err(MazeError::DimensionMismatch { expected: (self.width, self.height), found: (pos.width, pos.height) })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that a wall belongs to a maze with the same dimensions as this one.
+    fn check_wall(&self, wall: DynWall) -> Result<(), MazeError> {
+        if (wall.width, wall.height) != (self.width, self.height) {
+            Err(MazeError::DimensionMismatch { expected: (self.width, self.height), found: (wall.width, wall.height) })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Removes an existing wall from the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wall belongs to a maze of different dimensions, or doesn't
+    /// exist in the maze.
+    pub fn remove_wall(&mut self, wall: DynWall) -> Result<(), MazeError> {
+        self.check_wall(wall)?;
+        if self.walls.remove(wall.wall) {
+            Ok(())
+        } else {
+            Err(MazeError::WallNotFound { wall: wall.wall })
+        }
+    }
+
+    /// Adds a wall to the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The wall is outside the maze boundaries or on the exterior boundary
+    /// - The wall already exists in the maze
+    /// - Adding the wall would make the maze unsolvable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{DynWallMaze, DynPosition, Wall, Orientation};
+    ///
+    /// let start = DynPosition::new(0, 0, 3, 3).unwrap();
+    /// let end = DynPosition::new(2, 2, 3, 3).unwrap();
+    /// let mut maze = DynWallMaze::new(start, end).unwrap();
+    ///
+    /// assert!(maze.add_wall(Wall::new(0, 0, Orientation::Vertical)).is_ok());
+    /// assert!(maze.add_wall(Wall::new(0, 1, Orientation::Horizontal)).is_ok());
+    /// // This wall would seal off the start
+    /// assert!(maze.add_wall(Wall::new(0, 1, Orientation::Vertical)).is_err());
+    /// ```
+    pub fn add_wall(&mut self, wall: Wall) -> Result<(), MazeError> {
+        let interior_wall = DynWall::from_wall(wall, self.width, self.height)?;
+        self.add_interior_wall(interior_wall)
+    }
+
+    /// Adds a pre-validated interior wall to the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The wall belongs to a maze of different dimensions
+    /// - The wall already exists in the maze
+    /// - Adding the wall would make the maze unsolvable
+    pub fn add_interior_wall(&mut self, wall: DynWall) -> Result<(), MazeError> {
+        self.check_wall(wall)?;
+        if !self.walls.insert(wall.wall) {
+            Err(MazeError::WallAlreadyExists { wall: wall.wall })
+        } else {
+            let [cell1, cell2] = wall.surrounding_cells();
+            if !self.known_path.uses_step(cell1, cell2) {
+                return Ok(());
+            }
+            match self.solve() {
+                Ok(path) => {
+                    self.known_path = KnownPath::new(&path);
+                    Ok(())
+                },
+                Err(_) => {
+                    self.walls.remove(wall.wall);
+                    Err(MazeError::WallWouldMakeUnsolvable { wall: wall.wall })
+                }
+            }
+        }
+    }
+
+    /// Changes the start position of the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position belongs to a maze of different dimensions, is the
+    /// end position, or is walled off from the end.
+    pub fn move_start(&mut self, new_start: DynPosition) -> Result<(), MazeError> {
+        self.check_position(new_start)?;
+        if new_start == self.end {
+            return Err(MazeError::StartIsEnd { x: new_start.x, y: new_start.y });
+        }
+        if new_start == self.start {
+            return Ok(());
+        }
+        let old_start = std::mem::replace(&mut self.start, new_start);
+        match self.solve() {
+            Ok(path) => {
+                self.known_path = KnownPath::new(&path);
+                Ok(())
+            },
+            Err(_) => {
+                self.start = old_start;
+                Err(MazeError::PositionWouldMakeUnsolvable { x: new_start.x, y: new_start.y })
+            }
+        }
+    }
+
+    /// Swaps the start and end positions of the maze.
+    pub fn flip_start_end(&mut self) {
+        (self.start, self.end) = (self.end, self.start);
+    }
+
+    /// Changes the end position of the maze.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position belongs to a maze of different dimensions, is the
+    /// start position, or is walled off from the start.
+    pub fn move_end(&mut self, new_end: DynPosition) -> Result<(), MazeError> {
+        self.check_position(new_end)?;
+        self.flip_start_end();
+        let result = self.move_start(new_end);
+        self.flip_start_end();
+        result
+    }
+
+    /// Adds loops to the maze by removing walls at its dead ends, as with `WallMaze::braid`.
+    pub fn braid(&mut self, probability: f64, rng: &mut impl MazeRng) -> usize {
+        super::braid::braid(&mut self.walls, self.width, self.height, probability, rng)
+    }
+
+    /// Removes every loop from the maze by adding walls, as with `WallMaze::unbraid`.
+    pub fn unbraid(&mut self, rng: &mut impl MazeRng) -> usize {
+        let added = super::braid::unbraid(&mut self.walls, self.width, self.height, rng);
+        self.refresh_known_path();
+        added
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> From<InteriorPosition<WIDTH, HEIGHT>> for DynPosition {
+    fn from(pos: InteriorPosition<WIDTH, HEIGHT>) -> Self {
+        Self { x: pos.x, y: pos.y, width: WIDTH, height: HEIGHT }
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<DynPosition> for InteriorPosition<WIDTH, HEIGHT> {
+    type Error = MazeError;
+
+    fn try_from(pos: DynPosition) -> Result<Self, Self::Error> {
+        if (pos.width, pos.height) != (WIDTH, HEIGHT) {
+            Err(MazeError::DimensionMismatch { expected: (WIDTH, HEIGHT), found: (pos.width, pos.height) })
+        } else {
+            Ok(Self { x: pos.x, y: pos.y })
+        }
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> From<InteriorWall<WIDTH, HEIGHT>> for DynWall {
+    fn from(wall: InteriorWall<WIDTH, HEIGHT>) -> Self {
+        Self { wall: wall.wall, width: WIDTH, height: HEIGHT }
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<DynWall> for InteriorWall<WIDTH, HEIGHT> {
+    type Error = MazeError;
+
+    fn try_from(wall: DynWall) -> Result<Self, Self::Error> {
+        if (wall.width, wall.height) != (WIDTH, HEIGHT) {
+            Err(MazeError::DimensionMismatch { expected: (WIDTH, HEIGHT), found: (wall.width, wall.height) })
+        } else {
+            Ok(Self { wall: wall.wall })
+        }
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> From<WallMaze<WIDTH, HEIGHT>> for DynWallMaze {
+    fn from(maze: WallMaze<WIDTH, HEIGHT>) -> Self {
+        let mut dyn_maze = Self {
+            width: WIDTH,
+            height: HEIGHT,
+            start: maze.start.into(),
+            end: maze.end.into(),
+            walls: maze.walls,
+            known_path: KnownPath::unknown(),
+        };
+        dyn_maze.refresh_known_path();
+        dyn_maze
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<DynWallMaze> for WallMaze<WIDTH, HEIGHT> {
+    type Error = MazeError;
+
+    /// Converts a `DynWallMaze` into a `WallMaze<WIDTH, HEIGHT>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of the maze are not WIDTH × HEIGHT.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{DynWallMaze, DynPosition, WallMaze};
+    ///
+    /// let start = DynPosition::new(0, 0, 4, 6).unwrap();
+    /// let end = DynPosition::new(3, 5, 4, 6).unwrap();
+    /// let maze = DynWallMaze::new(start, end).unwrap();
+    ///
+    /// assert!(WallMaze::<4, 6>::try_from(maze.clone()).is_ok());
+    /// assert!(WallMaze::<6, 4>::try_from(maze).is_err());
+    /// ```
+    fn try_from(maze: DynWallMaze) -> Result<Self, Self::Error> {
+        if (maze.width, maze.height) != (WIDTH, HEIGHT) {
+            return Err(MazeError::DimensionMismatch { expected: (WIDTH, HEIGHT), found: (maze.width, maze.height) });
+        }
+        let mut const_maze = Self {
+            start: maze.start.try_into()?,
+            end: maze.end.try_into()?,
+            walls: maze.walls,
+            known_path: KnownPath::unknown(),
+        };
+        const_maze.refresh_known_path();
+        Ok(const_maze)
+    }
+}
+
+impl MazeCell for DynPosition {
+    fn adjacent_cells(&self) -> impl Iterator<Item = Self> + use<> {
+        self.adjacent_positions().into_iter()
+    }
+}
+
+impl PathHeuristic for DynPosition {
+    fn heuristic(&self, other: &Self) -> f64 {
+        self.min_distance(*other) as f64
+    }
+}
+
+impl MazeWall for DynWall {
+    type Cell = DynPosition;
+
+    /// Returns the two cells this wall separates, with the cell at the wall's own
+    /// coordinates first.
+    fn surrounding_cells(&self) -> [Self::Cell; 2] {
+        let first = DynPosition { x: self.wall.x, y: self.wall.y, width: self.width, height: self.height };
+        let second = match self.wall.orientation {
+            Horizontal => DynPosition { y: first.y + 1, ..first },
+            Vertical => DynPosition { x: first.x + 1, ..first },
+        };
+        [first, second]
+    }
+}
+
+impl Maze for DynWallMaze {
+    type Cell = DynPosition;
+
+    fn start(&self) -> Self::Cell {
+        self.start
+    }
+
+    fn end(&self) -> Self::Cell {
+        self.end
+    }
+
+    /// Yields nothing for a cell belonging to a maze of different dimensions.
+    fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell> {
+        let cell = *cell;
+        self.check_position(cell).is_ok()
+            .then(|| self.walls.open_neighbours((cell.x, cell.y), self.height))
+            .into_iter()
+            .flatten()
+            .map(move |(x, y)| DynPosition { x, y, ..cell })
+    }
+
+    fn try_solve(&self) -> Option<Vec<Self::Cell>> {
+        DynWallMaze::solve(self).ok()
+    }
+
+    fn cell_count(&self) -> Option<usize> {
+        Some(self.width * self.height)
+    }
+
+    /// Returns `None` for a cell belonging to a maze of different dimensions.
+    fn cell_index(&self, cell: &Self::Cell) -> Option<usize> {
+        self.check_position(*cell).is_ok().then(|| cell.y * self.width + cell.x)
+    }
+}
+
+impl WallMazeTrait for DynWallMaze {
+    type Wall = DynWall;
+
+    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        DynWallMaze::add_interior_wall(self, wall)
+    }
+
+    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        DynWallMaze::remove_wall(self, wall)
+    }
+
+    fn add_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        DynWallMaze::add_interior_wall(self, wall)
+    }
+
+    fn remove_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        DynWallMaze::remove_wall(self, wall)
+    }
+
+    /// Returns `false` for cells that are not adjacent, since no single wall lies between them,
+    /// and for cells belonging to a maze of different dimensions.
+    fn separated_by_wall(&self, cell1: &Self::Cell, cell2: &Self::Cell) -> bool {
+        cell1.separated_by_wall(*cell2, self).unwrap_or(false)
+    }
+}
+
+impl MutSolubleMaze for DynWallMaze {
+    fn move_start(&mut self, new_start: Self::Cell) -> Result<(), MazeError> {
+        DynWallMaze::move_start(self, new_start)
+    }
+
+    fn flip_start_end(&mut self) {
+        DynWallMaze::flip_start_end(self)
+    }
+
+    fn move_end(&mut self, new_end: Self::Cell) -> Result<(), MazeError> {
+        DynWallMaze::move_end(self, new_end)
+    }
+}
+
+impl MutSolubleWallMaze for DynWallMaze {
+    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        DynWallMaze::add_interior_wall(self, wall)
+    }
+
+    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        DynWallMaze::remove_wall(self, wall)
+    }
+}
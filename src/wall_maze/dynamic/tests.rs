@@ -0,0 +1,175 @@
+use super::*;
+
+#[test]
+fn test_dyn_position_creation() {
+    assert!(DynPosition::new(0, 0, 5, 3).is_ok());
+    assert!(DynPosition::new(4, 2, 5, 3).is_ok());
+    assert!(DynPosition::new(5, 0, 5, 3).is_err());
+    assert!(DynPosition::new(0, 3, 5, 3).is_err());
+    assert!(DynPosition::new(0, 0, 0, 0).is_err());
+}
+
+#[test]
+fn test_dyn_wall_creation() {
+    assert!(DynWall::new(0, 0, Horizontal, 5, 3).is_ok());
+    assert!(DynWall::new(3, 1, Vertical, 5, 3).is_ok());
+    assert!(DynWall::new(4, 1, Vertical, 5, 3).is_err());
+    assert!(DynWall::new(1, 2, Horizontal, 5, 3).is_err());
+}
+
+#[test]
+fn test_dyn_maze_solvability() {
+    let start = DynPosition::new(0, 0, 3, 3).unwrap();
+    let end = DynPosition::new(2, 2, 3, 3).unwrap();
+    let mut maze = DynWallMaze::new(start, end).unwrap();
+
+    assert!(maze.add_wall(Wall { x: 0, y: 0, orientation: Vertical }).is_ok());
+    assert!(maze.add_wall(Wall { x: 0, y: 1, orientation: Horizontal }).is_ok());
+    assert!(maze.add_wall(Wall { x: 0, y: 0, orientation: Vertical }).is_err());
+
+    // This wall would make maze unsolvable
+    assert!(maze.add_wall(Wall { x: 0, y: 1, orientation: Vertical }).is_err());
+
+    let path = maze.solve().unwrap();
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&end));
+}
+
+#[test]
+fn test_dyn_maze_rejects_mismatched_dimensions() {
+    let start = DynPosition::new(0, 0, 3, 3).unwrap();
+    let end = DynPosition::new(2, 2, 3, 3).unwrap();
+    let mut maze = DynWallMaze::new(start, end).unwrap();
+
+    let mismatch = |found| MazeError::DimensionMismatch { expected: (3, 3), found };
+    assert_eq!(DynWallMaze::new(start, DynPosition::new(2, 2, 4, 3).unwrap()), Err(mismatch((4, 3))));
+    assert_eq!(maze.add_interior_wall(DynWall::new(0, 0, Vertical, 4, 4).unwrap()), Err(mismatch((4, 4))));
+    assert_eq!(maze.remove_wall(DynWall::new(0, 0, Vertical, 4, 4).unwrap()), Err(mismatch((4, 4))));
+    let (far1, far2) = (DynPosition::new(5, 6, 100, 100).unwrap(), DynPosition::new(6, 6, 100, 100).unwrap());
+    assert_eq!(far1.separated_by_wall(far2, &maze), Err(mismatch((100, 100))));
+    assert_eq!(start.separated_by_wall(far2, &maze), Err(mismatch((100, 100))));
+    assert!(!crate::WallMaze::separated_by_wall(&maze, &far1, &far2));
+    assert_eq!(maze.move_start(DynPosition::new(1, 1, 3, 4).unwrap()), Err(mismatch((3, 4))));
+    assert_eq!(maze.move_end(DynPosition::new(1, 1, 4, 3).unwrap()), Err(mismatch((4, 3))));
+    assert!(maze.move_end(DynPosition::new(1, 1, 3, 3).unwrap()).is_ok());
+}
+
+#[test]
+fn test_dyn_matches_const_generic() {
+    let start = InteriorPosition::<6, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<6, 4>::new(5, 3).unwrap();
+    let mut maze = WallMaze::<6, 4>::new(start, end).unwrap();
+    let mut dyn_maze = DynWallMaze::new(start.into(), end.into()).unwrap();
+
+    for x in 0..6 {
+        for y in 0..4 {
+            for orientation in [Horizontal, Vertical] {
+                let wall = Wall::new(x, y, orientation);
+                assert_eq!(maze.add_wall(wall).is_ok(), dyn_maze.add_wall(wall).is_ok());
+            }
+        }
+    }
+
+    let path = maze.solve().unwrap();
+    let dyn_path = dyn_maze.solve().unwrap();
+    assert_eq!(path.into_iter().map(DynPosition::from).collect::<Vec<_>>(), dyn_path);
+    assert_eq!(DynWallMaze::from(maze.clone()), dyn_maze);
+    assert_eq!(WallMaze::<6, 4>::try_from(dyn_maze.clone()), Ok(maze));
+    assert_eq!(
+        WallMaze::<4, 6>::try_from(dyn_maze),
+        Err(MazeError::DimensionMismatch { expected: (4, 6), found: (6, 4) })
+    );
+}
+
+#[test]
+fn test_dyn_conversions() {
+    let pos = InteriorPosition::<5, 7>::new(4, 6).unwrap();
+    let dyn_pos = DynPosition::from(pos);
+    assert_eq!((dyn_pos.get_width(), dyn_pos.get_height()), (5, 7));
+    assert_eq!(InteriorPosition::<5, 7>::try_from(dyn_pos), Ok(pos));
+    assert!(InteriorPosition::<7, 5>::try_from(dyn_pos).is_err());
+
+    let wall = InteriorWall::<5, 7>::new(3, 5, Horizontal).unwrap();
+    let dyn_wall = DynWall::from(wall);
+    assert_eq!(dyn_wall.get_wall(), wall.get_wall());
+    assert_eq!(InteriorWall::<5, 7>::try_from(dyn_wall), Ok(wall));
+    assert!(InteriorWall::<5, 5>::try_from(dyn_wall).is_err());
+}
+
+#[test]
+fn test_dyn_shifted_by() {
+    let pos = DynPosition::new(0, 1, 2, 2).unwrap();
+    assert_eq!(pos.shifted_by(Right), DynPosition::new(1, 1, 2, 2));
+    assert!(pos.shifted_by(Left).is_err());
+    assert!(pos.shifted_by(Down).is_err());
+    assert_eq!(pos.shifted_by(Up), DynPosition::new(0, 0, 2, 2));
+}
+
+#[test]
+fn test_dyn_move_start_end_into_enclosed_cell() {
+    let start = DynPosition::new(0, 0, 5, 5).unwrap();
+    let end = DynPosition::new(4, 4, 5, 5).unwrap();
+    let walls = vec![
+        DynWall::new(1, 2, Vertical, 5, 5).unwrap(),
+        DynWall::new(2, 2, Vertical, 5, 5).unwrap(),
+        DynWall::new(2, 1, Horizontal, 5, 5).unwrap(),
+        DynWall::new(2, 2, Horizontal, 5, 5).unwrap(),
+    ];
+    let mut maze = DynWallMaze::from_walls(start, end, walls).unwrap();
+    let enclosed = DynPosition::new(2, 2, 5, 5).unwrap();
+
+    assert_eq!(maze.move_start(enclosed), Err(MazeError::PositionWouldMakeUnsolvable { x: 2, y: 2 }));
+    assert_eq!(maze.move_end(enclosed), Err(MazeError::PositionWouldMakeUnsolvable { x: 2, y: 2 }));
+    assert_eq!(maze.move_start(end), Err(MazeError::StartIsEnd { x: 4, y: 4 }));
+    assert_eq!(maze.move_end(start), Err(MazeError::StartIsEnd { x: 0, y: 0 }));
+    assert!(maze.move_start(DynPosition::new(2, 1, 5, 5).unwrap()).is_ok());
+    assert_eq!(maze.solve().unwrap().first(), Some(&DynPosition::new(2, 1, 5, 5).unwrap()));
+}
+
+#[test]
+fn test_dyn_queries_match_const_generic() {
+    let start = InteriorPosition::<5, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<5, 4>::new(4, 3).unwrap();
+    let walls = vec![
+        InteriorWall::new(0, 0, Vertical).unwrap(),
+        InteriorWall::new(1, 1, Horizontal).unwrap(),
+        InteriorWall::new(3, 2, Vertical).unwrap(),
+        InteriorWall::new(2, 0, Horizontal).unwrap(),
+    ];
+    let maze = WallMaze::from_walls(start, end, walls).unwrap();
+    let dyn_maze = DynWallMaze::from(maze.clone());
+
+    assert_eq!(dyn_maze.get_start(), DynPosition::from(maze.get_start()));
+    assert_eq!(dyn_maze.get_end(), DynPosition::from(maze.get_end()));
+    assert_eq!(DynWall::all(5, 4).collect::<Vec<_>>(), InteriorWall::<5, 4>::all().map(DynWall::from).collect::<Vec<_>>());
+    assert_eq!(dyn_maze.walls().collect::<Vec<_>>(), maze.walls().map(DynWall::from).collect::<Vec<_>>());
+    assert_eq!(
+        dyn_maze.passages().collect::<Vec<_>>(),
+        maze.passages().map(|cells| cells.map(DynPosition::from)).collect::<Vec<_>>()
+    );
+    for wall in InteriorWall::<5, 4>::all() {
+        assert_eq!(dyn_maze.has_wall(wall.into()), maze.has_wall(wall));
+    }
+    for pos in InteriorWall::<5, 4>::all().flat_map(|wall| wall.surrounding_cells()) {
+        let expected: Vec<DynPosition> = maze.open_neighbours(pos).into_iter().map(DynPosition::from).collect();
+        assert_eq!(dyn_maze.open_neighbours(pos.into()), Ok(expected));
+    }
+
+    assert!(!dyn_maze.has_wall(DynWall::new(0, 0, Vertical, 5, 5).unwrap()));
+    assert!(dyn_maze.open_neighbours(DynPosition::new(0, 0, 4, 5).unwrap()).is_err());
+}
+
+#[test]
+fn test_dyn_braid_matches_const_generic() {
+    use crate::rng::Xoshiro256StarStar;
+
+    let start = InteriorPosition::<8, 6>::new(0, 0).unwrap();
+    let end = InteriorPosition::<8, 6>::new(7, 5).unwrap();
+    let mut maze = crate::generate::kruskal(start, end, &mut Xoshiro256StarStar::new(5)).unwrap();
+    let mut dyn_maze = DynWallMaze::from(maze.clone());
+
+    assert_eq!(dyn_maze.braid(0.7, &mut Xoshiro256StarStar::new(6)), maze.braid(0.7, &mut Xoshiro256StarStar::new(6)));
+    assert_eq!(dyn_maze, DynWallMaze::from(maze.clone()));
+    assert_eq!(dyn_maze.unbraid(&mut Xoshiro256StarStar::new(7)), maze.unbraid(&mut Xoshiro256StarStar::new(7)));
+    assert_eq!(dyn_maze, DynWallMaze::from(maze));
+}
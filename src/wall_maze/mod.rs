@@ -1,38 +1,49 @@
-/// This module implements a grid-based maze with infinitely thin walls.
-///
-/// # Coordinate System
-///
-/// The maze is structured on a grid of dimensions WIDTH × HEIGHT.
-/// Positions are represented by (x, y) coordinates where:
-/// - x increases downward from 0 (to WIDTH-1)
-/// - y increases rightward from 0 (to HEIGHT-1)
-///
-/// # Wall Representation
-///
-/// Walls are placed between adjacent cells and are defined by:
-/// - An (x, y) coordinate of a cell
-/// - An orientation (horizontal or vertical)
-///
-/// A vertical wall at position (x, y) lies between cell (x, y) and (x+1, y),
-/// positioned to the right of the cell at (x, y).
-///
-/// A horizontal wall at position (x, y) lies between cell (x, y) and (x, y+1),
-/// positioned below the cell at (x, y).
-///
-/// # Solvability Guarantee
-///
-/// The maze maintains a guarantee of solvability at all times:
-/// - When adding a wall, the module verifies the maze remains solvable
-/// - If a wall would make the maze unsolvable, the addition is automatically rejected
-///
-/// The maze provides functionality to find paths from start to end,
-/// determine if positions are separated by walls, and move between adjacent positions.
-
+//! This module implements a grid-based maze with infinitely thin walls.
+//!
+//! # Coordinate System
+//!
+//! The maze is structured on a grid of dimensions WIDTH × HEIGHT.
+//! Positions are represented by (x, y) coordinates where:
+//! - x increases downward from 0 (to WIDTH-1)
+//! - y increases rightward from 0 (to HEIGHT-1)
+//!
+//! # Wall Representation
+//!
+//! Walls are placed between adjacent cells and are defined by:
+//! - An (x, y) coordinate of a cell
+//! - An orientation (horizontal or vertical)
+//!
+//! A vertical wall at position (x, y) lies between cell (x, y) and (x+1, y),
+//! positioned to the right of the cell at (x, y).
+//!
+//! A horizontal wall at position (x, y) lies between cell (x, y) and (x, y+1),
+//! positioned below the cell at (x, y).
+//!
+//! # Solvability Guarantee
+//!
+//! The maze maintains a guarantee of solvability at all times:
+//! - When adding a wall, the module verifies the maze remains solvable
+//! - If a wall would make the maze unsolvable, the addition is automatically rejected
+//!
+//! The maze provides functionality to find paths from start to end,
+//! determine if positions are separated by walls, and move between adjacent positions.
 
-use std::collections::HashMap;
+use crate::{Maze, MazeCell, MazeError, MazeWall, MutSolubleMaze, MutSolubleWallMaze, PathHeuristic};
+use crate::WallMaze as WallMazeTrait;
+use crate::rng::MazeRng;
+use crate::solve::{AStar, SearchSteps, Solution, Solver};
+use std::hash::{Hash, Hasher};
+use search::KnownPath;
+use wall_set::WallSet;
 #[cfg(test)]
 mod tests;
 
+mod braid;
+mod dynamic;
+mod search;
+mod wall_set;
+pub use dynamic::{DynPosition, DynWall, DynWallMaze};
+
 /// Represents the orientation of a wall in the maze.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Orientation {
@@ -107,19 +118,12 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorWall<WIDTH, HEIGHT> {
     /// let boundary_wall = InteriorWall::<5, 5>::new(4, 2, Orientation::Vertical);
     /// assert!(boundary_wall.is_err());
     /// ```
-    pub fn new(x: usize, y: usize, orientation: Orientation) -> Result<Self, String> {
-        if x >= WIDTH {
-            Err(format!("x coordinate {} is out of bounds for width {}", x, WIDTH))
-        } else if y >= HEIGHT {
-            Err(format!("y coordinate {} is out of bounds for height {}", y, HEIGHT))
+    pub fn new(x: usize, y: usize, orientation: Orientation) -> Result<Self, MazeError> {
+        let wall = Wall { x, y, orientation };
+        if x >= WIDTH || y >= HEIGHT || (x == WIDTH - 1 && orientation == Vertical) || (y == HEIGHT - 1 && orientation == Horizontal) {
+            Err(MazeError::WallOutOfBounds { wall, width: WIDTH, height: HEIGHT })
         } else {
-            if x == WIDTH - 1 && orientation == Vertical {
-                Err(format!("x coordinate {} is out of bounds for width {} (the wall would be on the edge of the maze)", x, WIDTH))
-            } else if y == HEIGHT - 1 && orientation == Horizontal {
-                Err(format!("y coordinate {} is out of bounds for height {} (the wall would be on the edge of the maze)", y, HEIGHT))
-            } else {
-                Ok(Self { wall: Wall { x, y, orientation } })
-            }
+            Ok(Self { wall })
         }
     }
 
@@ -138,7 +142,7 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorWall<WIDTH, HEIGHT> {
     /// let interior_wall = InteriorWall::<5, 5>::from_wall(raw_wall);
     /// assert!(interior_wall.is_ok());
     /// ```
-    pub fn from_wall(wall: Wall) -> Result<Self, String> {
+    pub fn from_wall(wall: Wall) -> Result<Self, MazeError> {
         Self::new(wall.x, wall.y, wall.orientation)
     }
 
@@ -164,23 +168,8 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorWall<WIDTH, HEIGHT> {
     /// let invalid_wall = InteriorWall::<5, 5>::from_position_and_orientation(pos2, Orientation::Horizontal);
     /// assert!(invalid_wall.is_err());
     /// ```
-    pub fn from_position_and_orientation(pos: InteriorPosition<WIDTH, HEIGHT>, orientation: Orientation) -> Result<Self, String> {
-        match orientation {
-            Horizontal => {
-                if pos.y == HEIGHT - 1 {
-                    Err(format!("y coordinate {} is out of bounds for height {} (the wall would be on the edge of the maze)", pos.y, HEIGHT))
-                } else {
-                    Self::new(pos.x, pos.y, orientation)
-                }
-            },
-            Vertical => {
-                if pos.x == WIDTH - 1 {
-                    Err(format!("x coordinate {} is out of bounds for width {} (the wall would be on the edge of the maze)", pos.x, WIDTH))
-                } else {
-                    Self::new(pos.x, pos.y, orientation)
-                }
-            }
-        }
+    pub fn from_position_and_orientation(pos: InteriorPosition<WIDTH, HEIGHT>, orientation: Orientation) -> Result<Self, MazeError> {
+        Self::new(pos.x, pos.y, orientation)
     }
 
     /// Returns the x-coordinate of this wall.
@@ -202,6 +191,23 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorWall<WIDTH, HEIGHT> {
     pub fn get_wall(self) -> Wall {
         self.wall
     }
+
+    /// Iterates over every possible interior wall of a WIDTH × HEIGHT maze.
+    ///
+    /// The horizontal walls come first, then the vertical walls, each in row-major order
+    /// of the cell they belong to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::InteriorWall;
+    ///
+    /// // 3 × 2 horizontal walls and 2 × 3 vertical walls
+    /// assert_eq!(InteriorWall::<3, 3>::all().count(), 12);
+    /// ```
+    pub fn all() -> impl Iterator<Item = Self> {
+        wall_set::interior_walls(WIDTH, HEIGHT).map(|wall| Self { wall })
+    }
 }
 
 /// A position guaranteed to be within the interior bounds of a maze.
@@ -228,17 +234,15 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
     /// ```
     /// use maze_solver::wall_maze::InteriorPosition;
     ///
-    /// let pos: Result<InteriorPosition<5, 5>, String> = InteriorPosition::new(2, 3);
+    /// let pos: Result<InteriorPosition<5, 5>, _> = InteriorPosition::new(2, 3);
     /// assert!(pos.is_ok());
     ///
     /// let invalid_pos = InteriorPosition::<5, 5>::new(5, 3);
     /// assert!(invalid_pos.is_err());
     /// ```
-    pub fn new(x: usize, y: usize) -> Result<Self, String> {
-        if x >= WIDTH {
-            Err(format!("x coordinate {} is out of bounds for width {}", x, WIDTH))
-        } else if y >= HEIGHT {
-            Err(format!("y coordinate {} is out of bounds for height {}", y, HEIGHT))
+    pub fn new(x: usize, y: usize) -> Result<Self, MazeError> {
+        if x >= WIDTH || y >= HEIGHT {
+            Err(MazeError::PositionOutOfBounds { x, y, width: WIDTH, height: HEIGHT })
         } else {
             Ok(Self { x, y })
         }
@@ -375,14 +379,16 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
     /// let pos3 = InteriorPosition::<3, 3>::new(0, 0).unwrap();
     /// assert!(pos1.separated_by_wall(pos3, &maze).is_err());
     /// ```
-    pub fn separated_by_wall(self, other: Self, maze: &WallMaze<WIDTH,HEIGHT>) -> Result<bool, String> {
-        if !self.adjacent_to(other) {
-            Err(format!("Positions {:?} and {:?} are not adjacent", self, other))
+    pub fn separated_by_wall(self, other: Self, maze: &WallMaze<WIDTH,HEIGHT>) -> Result<bool, MazeError> {
+        if self == other {
+            Err(MazeError::SamePosition { x: self.x, y: self.y })
+        } else if !self.adjacent_to(other) {
+            Err(MazeError::NotAdjacent { first: (self.x, self.y), second: (other.x, other.y) })
         } else {
             match self.x.cmp(&other.x) {
                 std::cmp::Ordering::Less => {
                     let proposed_wall = InteriorWall::from_position_and_orientation(self, Vertical)?;
-                    if maze.walls.contains(&proposed_wall) {
+                    if maze.walls.contains(proposed_wall.wall) {
                         Ok(true)
                     } else {
                         Ok(false)
@@ -390,7 +396,7 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
                 },
                 std::cmp::Ordering::Greater => {
                     let proposed_wall = InteriorWall::from_position_and_orientation(other, Vertical)?;
-                    if maze.walls.contains(&proposed_wall) {
+                    if maze.walls.contains(proposed_wall.wall) {
                         Ok(true)
                     } else {
                         Ok(false)
@@ -400,7 +406,7 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
                     match self.y.cmp(&other.y) {
                         std::cmp::Ordering::Less => {
                             let proposed_wall = InteriorWall::from_position_and_orientation(self, Horizontal)?;
-                            if maze.walls.contains(&proposed_wall) {
+                            if maze.walls.contains(proposed_wall.wall) {
                                 Ok(true)
                             } else {
                                 Ok(false)
@@ -408,13 +414,13 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
                         },
                         std::cmp::Ordering::Greater => {
                             let proposed_wall = InteriorWall::from_position_and_orientation(other, Horizontal)?;
-                            if maze.walls.contains(&proposed_wall) {
+                            if maze.walls.contains(proposed_wall.wall) {
                                 Ok(true)
                             } else {
                                 Ok(false)
                             }
                         },
-                        std::cmp::Ordering::Equal => Err(format!("Positions {:?} and {:?} are the same", self, other)),
+                        std::cmp::Ordering::Equal => Err(MazeError::SamePosition { x: self.x, y: self.y }),
                     }
                 },
             }
@@ -486,34 +492,25 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
     /// let up_pos = corner.shifted_by(Direction::Up);
     /// assert!(up_pos.is_err());
     /// ```
-    pub fn shifted_by(self, direction: Direction) -> Result<Self, String> {
+    pub fn shifted_by(self, direction: Direction) -> Result<Self, MazeError> {
+        let out_of_bounds = MazeError::MoveOutOfBounds { x: self.x, y: self.y, direction, width: WIDTH, height: HEIGHT };
         match direction {
             Up => {
                 if self.y == 0 {
-                    Err(format!("Cannot move up from position ({}, {})", self.x, self.y))
+                    Err(out_of_bounds)
                 } else {
                     Self::new(self.x, self.y - 1)
                 }
             },
-            Down => {
-                match Self::new(self.x, self.y + 1) {
-                    Ok(x) => Ok(x),
-                    Err(_) => Err(format!("Cannot move down from position ({}, {}). This would leave the bottom of the maze of height {}", self.x, self.y, HEIGHT)),
-                }
-            },
+            Down => Self::new(self.x, self.y + 1).map_err(|_| out_of_bounds),
             Left => {
                 if self.x == 0 {
-                    Err(format!("Cannot move left from position ({}, {})", self.x, self.y))
+                    Err(out_of_bounds)
                 } else {
                     Self::new(self.x - 1, self.y)
                 }
             },
-            Right => {
-                match Self::new(self.x + 1, self.y) {
-                    Ok(x) => Ok(x),
-                    Err(_) => Err(format!("Cannot move right from position ({}, {}). This would leave the right side of the maze of width {}", self.x, self.y, WIDTH)),
-                }
-            }
+            Right => Self::new(self.x + 1, self.y).map_err(|_| out_of_bounds),
         }
     }
 
@@ -540,7 +537,7 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
     /// assert!(corner.move_by(Direction::Left).is_err());
     /// assert_eq!(corner.get_x(), 0); // Position remains unchanged
     /// ```
-    pub fn move_by(&mut self, direction: Direction) -> Result<(), String> {
+    pub fn move_by(&mut self, direction: Direction) -> Result<(), MazeError> {
         let new_pos = self.shifted_by(direction)?;
         *self = new_pos;
         Ok(())
@@ -552,11 +549,36 @@ impl<const WIDTH: usize, const HEIGHT: usize> InteriorPosition<WIDTH, HEIGHT> {
 /// The maze consists of a grid of positions with dimensions WIDTH × HEIGHT,
 /// a start position, an end position, and a collection of walls. The maze
 /// guarantees that there is always a path from the start to the end position.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Walls are stored as one bit per possible wall, so looking up, adding and removing
+/// a wall take constant time, and mazes with the same walls are equal however the
+/// walls were added.
+///
+/// To keep that guarantee cheap, the maze remembers one path from start to end and
+/// only searches again when a new wall blocks a step of it. This path is not part of
+/// the maze's identity and is ignored by equality and hashing.
+#[derive(Debug, Clone)]
 pub struct WallMaze<const WIDTH: usize, const HEIGHT: usize> {
     start: InteriorPosition<WIDTH, HEIGHT>,
     end: InteriorPosition<WIDTH, HEIGHT>,
-    walls: Vec<InteriorWall<WIDTH, HEIGHT>>
+    walls: WallSet,
+    known_path: KnownPath<InteriorPosition<WIDTH, HEIGHT>>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> PartialEq for WallMaze<WIDTH, HEIGHT> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.walls == other.walls
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Eq for WallMaze<WIDTH, HEIGHT> {}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Hash for WallMaze<WIDTH, HEIGHT> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.walls.hash(state);
+    }
 }
 
 impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
@@ -582,15 +604,8 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// let invalid_maze = WallMaze::<5, 5>::new(same_pos, same_pos);
     /// assert!(invalid_maze.is_err());
     /// ```
-    pub fn new(start: InteriorPosition<WIDTH,HEIGHT>, end: InteriorPosition<WIDTH,HEIGHT>) -> Result<Self, String> {
-        if start == end {
-            return Err(format!("Start position cannot be the same as end position"));
-        }
-        Ok(Self {
-            start,
-            end,
-            walls: Vec::new(),
-        })
+    pub fn new(start: InteriorPosition<WIDTH,HEIGHT>, end: InteriorPosition<WIDTH,HEIGHT>) -> Result<Self, MazeError> {
+        Self::from_walls(start, end, Vec::new())
     }
 
     /// Creates a new `WallMaze<WIDTH, HEIGHT>` with the specified start, end, and walls.
@@ -618,24 +633,127 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// let maze = WallMaze::<5, 5>::from_walls(start, end, walls);
     /// assert!(maze.is_ok());
     /// ```
-    pub fn from_walls(start: InteriorPosition<WIDTH, HEIGHT>, end: InteriorPosition<WIDTH, HEIGHT>, walls: Vec<InteriorWall<WIDTH, HEIGHT>>) -> Result<Self, String> {
-        let maze = WallMaze {
+    pub fn from_walls(start: InteriorPosition<WIDTH, HEIGHT>, end: InteriorPosition<WIDTH, HEIGHT>, walls: Vec<InteriorWall<WIDTH, HEIGHT>>) -> Result<Self, MazeError> {
+        if start == end {
+            return Err(MazeError::StartIsEnd { x: start.x, y: start.y });
+        }
+        let mut wall_set = WallSet::new(WIDTH, HEIGHT);
+        for wall in walls {
+            wall_set.insert(wall.wall);
+        }
+        let mut maze = WallMaze {
             start,
             end,
-            walls,
+            walls: wall_set,
+            known_path: KnownPath::unknown(),
         };
-        if !maze.solveable() {
-            Err(format!("Maze is not solvable with the given walls"))
+        if !maze.refresh_known_path() {
+            Err(MazeError::Unsolvable)
         } else {
             Ok(maze)
         }
     }
 
+    /// Returns the start position of the maze.
+    pub fn get_start(&self) -> InteriorPosition<WIDTH, HEIGHT> {
+        self.start
+    }
+
+    /// Returns the end position of the maze.
+    pub fn get_end(&self) -> InteriorPosition<WIDTH, HEIGHT> {
+        self.end
+    }
+
+    /// Returns `true` if the wall is in the maze.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition, InteriorWall, Orientation};
+    ///
+    /// let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    /// let wall = InteriorWall::<3, 3>::new(1, 1, Orientation::Vertical).unwrap();
+    /// let maze = WallMaze::<3, 3>::from_walls(start, end, vec![wall]).unwrap();
+    ///
+    /// assert!(maze.has_wall(wall));
+    /// assert!(!maze.has_wall(InteriorWall::new(1, 1, Orientation::Horizontal).unwrap()));
+    /// ```
+    pub fn has_wall(&self, wall: InteriorWall<WIDTH, HEIGHT>) -> bool {
+        self.walls.contains(wall.wall)
+    }
+
+    /// Iterates over the walls in the maze, in the order of `InteriorWall::all`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition, InteriorWall, Orientation};
+    ///
+    /// let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    /// let walls = vec![
+    ///     InteriorWall::<3, 3>::new(0, 1, Orientation::Vertical).unwrap(),
+    ///     InteriorWall::<3, 3>::new(1, 0, Orientation::Horizontal).unwrap(),
+    /// ];
+    /// let maze = WallMaze::<3, 3>::from_walls(start, end, walls.clone()).unwrap();
+    ///
+    /// // Horizontal walls are listed first
+    /// assert_eq!(maze.walls().collect::<Vec<_>>(), vec![walls[1], walls[0]]);
+    /// ```
+    pub fn walls(&self) -> impl Iterator<Item = InteriorWall<WIDTH, HEIGHT>> {
+        self.walls.iter().map(|wall| InteriorWall { wall })
+    }
+
+    /// Iterates over the open passages of the maze: each pair of adjacent positions with no
+    /// wall between them, given as the two cells either side of the missing wall.
+    ///
+    /// Passages come in the order of `InteriorWall::all`, with the cells ordered as by
+    /// `MazeWall::surrounding_cells`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition, InteriorWall, Orientation};
+    ///
+    /// let start = InteriorPosition::<2, 2>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<2, 2>::new(1, 1).unwrap();
+    /// let wall = InteriorWall::<2, 2>::new(0, 0, Orientation::Vertical).unwrap();
+    /// let maze = WallMaze::<2, 2>::from_walls(start, end, vec![wall]).unwrap();
+    ///
+    /// // Four possible walls, one of which is present
+    /// assert_eq!(maze.passages().count(), 3);
+    /// ```
+    pub fn passages(&self) -> impl Iterator<Item = [InteriorPosition<WIDTH, HEIGHT>; 2]> {
+        InteriorWall::all()
+            .filter(|&wall| !self.has_wall(wall))
+            .map(|wall| wall.surrounding_cells())
+    }
+
+    /// Returns the positions adjacent to `pos` that are not separated from it by a wall.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition, InteriorWall, Orientation};
+    ///
+    /// let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    /// let wall = InteriorWall::<3, 3>::new(0, 0, Orientation::Vertical).unwrap();
+    /// let maze = WallMaze::<3, 3>::from_walls(start, end, vec![wall]).unwrap();
+    ///
+    /// assert_eq!(maze.open_neighbours(start), vec![InteriorPosition::new(0, 1).unwrap()]);
+    /// ```
+    pub fn open_neighbours(&self, pos: InteriorPosition<WIDTH, HEIGHT>) -> Vec<InteriorPosition<WIDTH, HEIGHT>> {
+        self.walls.open_neighbours((pos.x, pos.y), HEIGHT).map(|(x, y)| InteriorPosition { x, y }).collect()
+    }
+
     /// Finds a path from the start to the end position in the maze.
     ///
     /// Returns a vector of positions representing the path, including both start and end positions.
     /// The path is guaranteed to be valid, moving only between adjacent positions that
-    /// are not separated by walls.
+    /// are not separated by walls, and to be a shortest such path. It is found by the A* search
+    /// of `solve::AStar`, using the Manhattan distance to the end as its heuristic.
     ///
     /// # Errors
     ///
@@ -661,47 +779,77 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// assert_eq!(path.first(), Some(&start));
     /// assert_eq!(path.last(), Some(&end));
     /// ```
-    pub fn solve(&self) -> Result<Vec<InteriorPosition<WIDTH, HEIGHT>>, String> {
-        let mut unchecked = vec![self.start];
-        let mut path_to = HashMap::new();
-        path_to.insert(self.start, Vec::from([self.start]));
-        loop {
-            if path_to.contains_key(&self.end) {
-                break;
-            }
-            if unchecked.is_empty() {
-                return Err(format!("No path found from start to end"));
-            }
-            let value = |pos: InteriorPosition<WIDTH, HEIGHT>| {
-                pos.min_distance(self.end) + &path_to.get(&pos).unwrap().len()
-            };
-            unchecked.sort_by(|&a, &b| {
-                value(a).cmp(&value(b)).reverse()
-            });
-            let next = unchecked.pop().unwrap();
-            let adjacents = next.adjacent_positions();
-            for adj in adjacents {
-                if path_to.contains_key(&adj) {
-                    continue;
-                }
-                if adj.separated_by_wall(next, self).unwrap() {
-                    continue;
-                }
-                unchecked.push(adj);
-                let mut new_path = path_to.get(&next).unwrap().clone();
-                new_path.push(adj);
-                path_to.insert(adj, new_path);
-            }
-        }
-        Ok(path_to.get(&self.end).unwrap().clone())
+    pub fn solve(&self) -> Result<Vec<InteriorPosition<WIDTH, HEIGHT>>, MazeError> {
+        AStar.solve(self).path.ok_or(MazeError::Unsolvable)
+    }
+
+    /// Finds a path from the start to the end as `solve` does, and measures the work done finding it.
+    /// This is the same search, and so the same measurements, as the "a-star" row of `solve::compare`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition};
+    ///
+    /// let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+    /// let maze = WallMaze::<10, 10>::new(start, end).unwrap();
+    ///
+    /// let solution = maze.solve_with_stats();
+    /// assert_eq!(solution.path, Some(maze.solve().unwrap()));
+    /// assert_eq!(solution.stats.path_length, Some(18));
+    /// // With nothing in the way, the search heads straight for the end
+    /// assert_eq!(solution.stats.expanded, 18);
+    /// ```
+    pub fn solve_with_stats(&self) -> Solution<InteriorPosition<WIDTH, HEIGHT>> {
+        AStar.solve(self)
     }
 
-    /// Returns whether the maze can be solved from start to end.
+    /// Returns the steps `solve` takes to find its path, one at a time, as given by `AStar::steps`.
     ///
-    /// This is a utility method used internally to ensure the maze remains solvable
-    /// when adding or removing walls or changing start/end positions.
-    fn solveable(&self) -> bool {
-        self.solve().is_ok()
+    /// `solve` runs the A* search of `solve::AStar`, which keeps a frontier of positions ordered by the number of
+    /// steps to reach them plus their Manhattan distance to the end. The search begins by
+    /// discovering the start, then repeatedly expands the first position of the frontier,
+    /// discovering each neighbour it has found a shorter path to. After the start and after each
+    /// expanded position comes a snapshot of the frontier. The search finishes with the path it
+    /// found, which is the path `solve` returns, or with `SolveEvent::Failed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::solve::SolveEvent;
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition};
+    ///
+    /// let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<3, 3>::new(2, 0).unwrap();
+    /// let maze = WallMaze::<3, 3>::new(start, end).unwrap();
+    /// let pos = |x, y| InteriorPosition::<3, 3>::new(x, y).unwrap();
+    ///
+    /// let events: Vec<_> = maze.solve_steps().collect();
+    /// assert_eq!(events[..4], [
+    ///     SolveEvent::Discovered(start),
+    ///     SolveEvent::Frontier(vec![start]),
+    ///     SolveEvent::Expanded(start),
+    ///     SolveEvent::Discovered(pos(1, 0)),
+    /// ]);
+    /// assert_eq!(events.last(), Some(&SolveEvent::Found(maze.solve().unwrap())));
+    /// ```
+    pub fn solve_steps(&self) -> SearchSteps<'_, Self> {
+        AStar::steps(self)
+    }
+
+    /// Searches for a new path from start to end and remembers it, returning whether one was found.
+    fn refresh_known_path(&mut self) -> bool {
+        match self.solve() {
+            Ok(path) => {
+                self.known_path = KnownPath::new(&path);
+                true
+            },
+            Err(_) => {
+                self.known_path = KnownPath::unknown();
+                false
+            }
+        }
     }
 
     /// Removes an existing wall from the maze.
@@ -729,12 +877,11 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// // Trying to remove it again fails
     /// assert!(maze.remove_wall(wall).is_err());
     /// ```
-    pub fn remove_wall(&mut self, interior_wall: InteriorWall<WIDTH, HEIGHT>) -> Result<(), String> {
-        if let Some(pos) = self.walls.iter().position(|w| *w == interior_wall) {
-            self.walls.remove(pos);
+    pub fn remove_wall(&mut self, interior_wall: InteriorWall<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+        if self.walls.remove(interior_wall.wall) {
             Ok(())
         } else {
-            Err(format!("Wall {:?} not found in the maze", interior_wall))
+            Err(MazeError::WallNotFound { wall: interior_wall.wall })
         }
     }
 
@@ -765,7 +912,7 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// // Adding the same wall again fails
     /// assert!(maze.add_wall(Wall::new(1, 1, Orientation::Horizontal)).is_err());
     /// ```
-    pub fn add_wall(&mut self, wall: Wall) -> Result<(), String> {
+    pub fn add_wall(&mut self, wall: Wall) -> Result<(), MazeError> {
         let interior_wall = InteriorWall::from_wall(wall)?;
         self.add_interior_wall(interior_wall)
     }
@@ -774,7 +921,9 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     ///
     /// Unlike `add_wall`, this method takes an `InteriorWall<WIDTH, HEIGHT>` which has 
     /// already been validated to be within the maze boundaries.
-    /// The method still verifies that adding this wall won't make the maze unsolvable.
+    /// The method still verifies that adding this wall won't make the maze unsolvable,
+    /// though it only needs to search for a path when the wall blocks the path the maze
+    /// currently knows about.
     ///
     /// # Errors
     ///
@@ -800,16 +949,23 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// // Adding the same wall again fails
     /// assert!(maze.add_interior_wall(wall).is_err());
     /// ```
-    pub fn add_interior_wall(&mut self, interior_wall: InteriorWall<WIDTH, HEIGHT>) -> Result<(), String> {
-        if self.walls.contains(&interior_wall) {
-            Err(format!("Wall {:?} already exists in the maze", interior_wall))
+    pub fn add_interior_wall(&mut self, interior_wall: InteriorWall<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+        if !self.walls.insert(interior_wall.wall) {
+            Err(MazeError::WallAlreadyExists { wall: interior_wall.wall })
         } else {
-            self.walls.push(interior_wall);
-            if self.solveable() {
-                Ok(())
-            } else {
-                self.walls.pop();
-                Err(format!("Wall {:?} would make the maze unsolvable", interior_wall))
+            let [cell1, cell2] = interior_wall.surrounding_cells();
+            if !self.known_path.uses_step(cell1, cell2) {
+                return Ok(());
+            }
+            match self.solve() {
+                Ok(path) => {
+                    self.known_path = KnownPath::new(&path);
+                    Ok(())
+                },
+                Err(_) => {
+                    self.walls.remove(interior_wall.wall);
+                    Err(MazeError::WallWouldMakeUnsolvable { wall: interior_wall.wall })
+                }
             }
         }
     }
@@ -817,12 +973,14 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// Changes the start position of the maze.
     ///
     /// This method verifies that the maze remains solvable after changing the
-    /// start position by checking if there's a valid path from the new start 
-    /// to the current start.
+    /// start position by searching for a path from the new start to the end
+    /// through the current walls. Moving the start to where it already is does nothing.
     ///
     /// # Errors
     ///
-    /// Returns an error if changing the start would make the maze unsolvable.
+    /// Returns an error if:
+    /// - The new start is the end position
+    /// - The new start is walled off from the end, so the maze would become unsolvable
     ///
     /// # Examples
     ///
@@ -841,18 +999,23 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// let new_start = InteriorPosition::<5, 5>::new(1, 1).unwrap();
     /// assert!(maze.move_start(new_start).is_ok());
     /// ```
-    pub fn move_start(&mut self, new_start: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), String> {
-        let start_to_new_start_maze = match WallMaze::new(new_start, self.start) {
-            Ok(maze) => maze,
-            Err(_) => {
-                return Ok(());
+    pub fn move_start(&mut self, new_start: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), MazeError> {
+        if new_start == self.end {
+            return Err(MazeError::StartIsEnd { x: new_start.x, y: new_start.y });
+        }
+        if new_start == self.start {
+            return Ok(());
+        }
+        let old_start = std::mem::replace(&mut self.start, new_start);
+        match self.solve() {
+            Ok(path) => {
+                self.known_path = KnownPath::new(&path);
+                Ok(())
             },
-        };
-        if start_to_new_start_maze.solveable() {
-            self.start = new_start;
-            Ok(())
-        } else {
-            Err(format!("New start position {:?} would make the maze unsolvable", new_start))
+            Err(_) => {
+                self.start = old_start;
+                Err(MazeError::PositionWouldMakeUnsolvable { x: new_start.x, y: new_start.y })
+            }
         }
     }
 
@@ -888,11 +1051,14 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// Changes the end position of the maze.
     ///
     /// This method verifies that the maze remains solvable after changing the
-    /// end position.
+    /// end position by searching for a path from the start to the new end
+    /// through the current walls. Moving the end to where it already is does nothing.
     ///
     /// # Errors
     ///
-    /// Returns an error if changing the end position would make the maze unsolvable.
+    /// Returns an error if:
+    /// - The new end is the start position
+    /// - The new end is walled off from the start, so the maze would become unsolvable
     ///
     /// # Examples
     ///
@@ -907,17 +1073,172 @@ impl<const WIDTH: usize, const HEIGHT: usize> WallMaze<WIDTH, HEIGHT> {
     /// let new_end = InteriorPosition::<5, 5>::new(3, 3).unwrap();
     /// assert!(maze.move_end(new_end).is_ok());
     /// ```
-    pub fn move_end(&mut self, new_end: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), String> {
+    pub fn move_end(&mut self, new_end: InteriorPosition<WIDTH, HEIGHT>) -> Result<(), MazeError> {
         self.flip_start_end();
-        match self.move_start(new_end) {
-            Ok(_) => {
-                self.flip_start_end();
-                Ok(())
-            },
-            Err(_) => {
-                self.flip_start_end();
-                Err(format!("New end position {:?} would make the maze unsolvable", new_end))
-            }
-        }
+        let result = self.move_start(new_end);
+        self.flip_start_end();
+        result
+    }
+
+    /// Adds loops to the maze by removing walls at its dead ends, returning the number of
+    /// walls removed.
+    ///
+    /// Each dead end (a position with exactly one open neighbour) is opened up with the given
+    /// probability, so 0.0 leaves the maze as it is and 1.0 leaves it with no dead ends at all.
+    /// Where it can, a dead end is opened into a neighbouring dead end, so that one wall removes
+    /// both. Braiding a perfect maze gives it multiple solutions, and since walls are only ever
+    /// removed the maze stays solvable.
+    ///
+    /// A position with only one neighbour in the whole grid, at the end of a maze one position
+    /// wide, cannot be opened up and remains a dead end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::generate::recursive_backtracker;
+    /// use maze_solver::rng::Xoshiro256StarStar;
+    /// use maze_solver::wall_maze::InteriorPosition;
+    ///
+    /// let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+    /// let mut rng = Xoshiro256StarStar::new(1);
+    /// let mut maze = recursive_backtracker(start, end, &mut rng).unwrap();
+    ///
+    /// let removed = maze.braid(1.0, &mut rng);
+    /// assert_eq!(maze.passages().count(), 99 + removed);
+    /// ```
+    pub fn braid(&mut self, probability: f64, rng: &mut impl MazeRng) -> usize {
+        braid::braid(&mut self.walls, WIDTH, HEIGHT, probability, rng)
+    }
+
+    /// Removes every loop from the maze by adding walls, returning the number of walls added.
+    ///
+    /// Passages are closed off in a random order until each pair of positions that were
+    /// connected is connected by exactly one path, so the maze stays solvable and, if all of
+    /// its positions were reachable, becomes a perfect maze.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maze_solver::rng::Xoshiro256StarStar;
+    /// use maze_solver::wall_maze::{WallMaze, InteriorPosition};
+    ///
+    /// let start = InteriorPosition::<10, 10>::new(0, 0).unwrap();
+    /// let end = InteriorPosition::<10, 10>::new(9, 9).unwrap();
+    /// let mut maze = WallMaze::<10, 10>::new(start, end).unwrap();
+    ///
+    /// // An empty maze is full of loops
+    /// maze.unbraid(&mut Xoshiro256StarStar::new(1));
+    /// assert_eq!(maze.passages().count(), 99);
+    /// assert!(maze.solve().is_ok());
+    /// ```
+    pub fn unbraid(&mut self, rng: &mut impl MazeRng) -> usize {
+        let added = braid::unbraid(&mut self.walls, WIDTH, HEIGHT, rng);
+        self.refresh_known_path();
+        added
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> MazeCell for InteriorPosition<WIDTH, HEIGHT> {
+    fn adjacent_cells(&self) -> impl Iterator<Item = Self> + use<WIDTH, HEIGHT> {
+        self.adjacent_positions().into_iter()
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> PathHeuristic for InteriorPosition<WIDTH, HEIGHT> {
+    fn heuristic(&self, other: &Self) -> f64 {
+        self.min_distance(*other) as f64
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> MazeWall for InteriorWall<WIDTH, HEIGHT> {
+    type Cell = InteriorPosition<WIDTH, HEIGHT>;
+
+    /// Returns the two cells this wall separates, with the cell at the wall's own
+    /// coordinates first.
+    fn surrounding_cells(&self) -> [Self::Cell; 2] {
+        let first = InteriorPosition { x: self.wall.x, y: self.wall.y };
+        let second = match self.wall.orientation {
+            Horizontal => InteriorPosition { x: self.wall.x, y: self.wall.y + 1 },
+            Vertical => InteriorPosition { x: self.wall.x + 1, y: self.wall.y },
+        };
+        [first, second]
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Maze for WallMaze<WIDTH, HEIGHT> {
+    type Cell = InteriorPosition<WIDTH, HEIGHT>;
+
+    fn start(&self) -> Self::Cell {
+        self.start
+    }
+
+    fn end(&self) -> Self::Cell {
+        self.end
+    }
+
+    fn passable_neighbours(&self, cell: &Self::Cell) -> impl Iterator<Item = Self::Cell> {
+        self.walls.open_neighbours((cell.x, cell.y), HEIGHT).map(|(x, y)| InteriorPosition { x, y })
+    }
+
+    fn try_solve(&self) -> Option<Vec<Self::Cell>> {
+        WallMaze::solve(self).ok()
+    }
+
+    fn cell_count(&self) -> Option<usize> {
+        Some(WIDTH * HEIGHT)
+    }
+
+    fn cell_index(&self, cell: &Self::Cell) -> Option<usize> {
+        Some(cell.y * WIDTH + cell.x)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> WallMazeTrait for WallMaze<WIDTH, HEIGHT> {
+    type Wall = InteriorWall<WIDTH, HEIGHT>;
+
+    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        WallMaze::add_interior_wall(self, wall)
+    }
+
+    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        WallMaze::remove_wall(self, wall)
+    }
+
+    fn add_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        WallMaze::add_interior_wall(self, wall)
+    }
+
+    fn remove_interior_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        WallMaze::remove_wall(self, wall)
+    }
+
+    /// Returns `false` for cells that are not adjacent, since no single wall lies between them.
+    fn separated_by_wall(&self, cell1: &Self::Cell, cell2: &Self::Cell) -> bool {
+        cell1.separated_by_wall(*cell2, self).unwrap_or(false)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> MutSolubleMaze for WallMaze<WIDTH, HEIGHT> {
+    fn move_start(&mut self, new_start: Self::Cell) -> Result<(), MazeError> {
+        WallMaze::move_start(self, new_start)
+    }
+
+    fn flip_start_end(&mut self) {
+        WallMaze::flip_start_end(self)
+    }
+
+    fn move_end(&mut self, new_end: Self::Cell) -> Result<(), MazeError> {
+        WallMaze::move_end(self, new_end)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> MutSolubleWallMaze for WallMaze<WIDTH, HEIGHT> {
+    fn add_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        WallMaze::add_interior_wall(self, wall)
+    }
+
+    fn remove_wall(&mut self, wall: Self::Wall) -> Result<(), MazeError> {
+        WallMaze::remove_wall(self, wall)
     }
 }
@@ -0,0 +1,37 @@
+//! Path bookkeeping shared by the const-generic and runtime-sized wall mazes.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The cells of a known path from the start to the end of a maze, indexed by their position along it.
+///
+/// Adding a wall can only make a maze unsolvable if it blocks a step of every path from start to end,
+/// so a wall that blocks no step of this path can be accepted without searching again. An empty
+/// `KnownPath` stands for a path that is not known, and is treated as using every step.
+#[derive(Debug, Clone)]
+pub(crate) struct KnownPath<T> {
+    index: HashMap<T, usize>,
+}
+
+impl<T: Copy + Eq + Hash> KnownPath<T> {
+    /// Indexes the cells of a path, which must not visit any cell twice.
+    pub(crate) fn new(path: &[T]) -> Self {
+        Self { index: path.iter().enumerate().map(|(i, &cell)| (cell, i)).collect() }
+    }
+
+    /// Returns a `KnownPath` for when no path is known.
+    pub(crate) fn unknown() -> Self {
+        Self { index: HashMap::new() }
+    }
+
+    /// Returns `true` if the path moves directly between `cell1` and `cell2`, in either direction.
+    pub(crate) fn uses_step(&self, cell1: T, cell2: T) -> bool {
+        if self.index.is_empty() {
+            return true;
+        }
+        match (self.index.get(&cell1), self.index.get(&cell2)) {
+            (Some(i), Some(j)) => i.abs_diff(*j) == 1,
+            _ => false,
+        }
+    }
+}
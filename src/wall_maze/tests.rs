@@ -1,4 +1,6 @@
 use super::*;
+use crate::solve::{SolveEvent, SolverStats};
+use crate::test_util::{assert_valid_path, exhaustive_shortest_steps};
 
 #[test]
 fn test_interior_position_creation() {
@@ -74,27 +76,8 @@ fn test_solve_maze() {
     let end = InteriorPosition::<WIDTH, HEIGHT>::new(4, 4).unwrap();
     let mut maze = WallMaze::<WIDTH, HEIGHT>::new(start, end).unwrap();
 
-    let walls = [
-        InteriorWall::new(0,0, Vertical).unwrap(),
-        InteriorWall::new(0,1, Vertical).unwrap(),
-        InteriorWall::new(0,3, Vertical).unwrap(),
-        InteriorWall::new(1,1, Horizontal).unwrap(),
-        InteriorWall::new(1,3, Vertical).unwrap(),
-        InteriorWall::new(1,3, Horizontal).unwrap(),
-        InteriorWall::new(2,0, Horizontal).unwrap(),
-        InteriorWall::new(2,1, Vertical).unwrap(),
-        InteriorWall::new(2,2, Vertical).unwrap(),
-        InteriorWall::new(2,2, Horizontal).unwrap(),
-        InteriorWall::new(2,3, Vertical).unwrap(),
-        InteriorWall::new(2,4, Vertical).unwrap(),
-        InteriorWall::new(3,1, Vertical).unwrap(),
-        InteriorWall::new(3,2, Vertical).unwrap(),
-        InteriorWall::new(3,3, Horizontal).unwrap(),
-        InteriorWall::new(4,2, Horizontal).unwrap()
-    ];
-
-    for wall in walls.iter() {
-        maze.add_interior_wall(wall.clone()).unwrap();
+    for wall in example_walls() {
+        maze.add_interior_wall(wall).unwrap();
     }
     let path = maze.solve().unwrap();
     
@@ -115,4 +98,671 @@ fn test_solve_maze() {
         InteriorPosition::new(4,3).unwrap(),
         InteriorPosition::new(4,4).unwrap()
     ]);
-}
\ No newline at end of file
+}
+
+/// The walls of the 5 × 5 maze solved by `test_solve_maze`, which winds from (0, 0) to (4, 4).
+fn example_walls() -> Vec<InteriorWall<5, 5>> {
+    vec![
+        InteriorWall::new(0,0, Vertical).unwrap(),
+        InteriorWall::new(0,1, Vertical).unwrap(),
+        InteriorWall::new(0,3, Vertical).unwrap(),
+        InteriorWall::new(1,1, Horizontal).unwrap(),
+        InteriorWall::new(1,3, Vertical).unwrap(),
+        InteriorWall::new(1,3, Horizontal).unwrap(),
+        InteriorWall::new(2,0, Horizontal).unwrap(),
+        InteriorWall::new(2,1, Vertical).unwrap(),
+        InteriorWall::new(2,2, Vertical).unwrap(),
+        InteriorWall::new(2,2, Horizontal).unwrap(),
+        InteriorWall::new(2,3, Vertical).unwrap(),
+        InteriorWall::new(2,4, Vertical).unwrap(),
+        InteriorWall::new(3,1, Vertical).unwrap(),
+        InteriorWall::new(3,2, Vertical).unwrap(),
+        InteriorWall::new(3,3, Horizontal).unwrap(),
+        InteriorWall::new(4,2, Horizontal).unwrap()
+    ]
+}
+
+fn example_maze() -> WallMaze<5, 5> {
+    let start = InteriorPosition::<5, 5>::new(0, 0).unwrap();
+    let end = InteriorPosition::<5, 5>::new(4, 4).unwrap();
+    WallMaze::from_walls(start, end, example_walls()).unwrap()
+}
+
+fn trait_solution<M: Maze>(maze: &M) -> Option<Vec<M::Cell>> {
+    maze.try_solve()
+}
+
+#[test]
+fn test_trait_solvers_agree_with_inherent() {
+    use crate::HeuristicWallMaze;
+
+    let maze = example_maze();
+    let inherent = maze.solve().unwrap();
+
+    assert_eq!(trait_solution(&maze), Some(inherent.clone()));
+
+    let dfs = WallMazeTrait::try_solve(&maze).unwrap();
+    assert_valid_path(&maze, &dfs);
+
+    let heuristic = HeuristicWallMaze::try_solve(&maze).unwrap();
+    assert_valid_path(&maze, &heuristic);
+    assert_eq!(heuristic.len(), inherent.len());
+}
+
+#[test]
+fn test_trait_wall_edits() {
+    let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    let mut maze = WallMaze::<3, 3>::new(start, end).unwrap();
+
+    let wall = InteriorWall::new(0, 0, Vertical).unwrap();
+    let [left, right] = wall.surrounding_cells();
+    assert_eq!(left, InteriorPosition::new(0, 0).unwrap());
+    assert_eq!(right, InteriorPosition::new(1, 0).unwrap());
+
+    assert!(WallMazeTrait::add_wall(&mut maze, wall).is_ok());
+    assert!(WallMazeTrait::separated_by_wall(&maze, &left, &right));
+    assert!(!WallMazeTrait::separated_by_wall(&maze, &left, &end));
+    assert!(MutSolubleWallMaze::add_wall(&mut maze, InteriorWall::new(0, 1, Horizontal).unwrap()).is_ok());
+
+    // The trait path enforces the same solvability guarantee as the inherent one.
+    assert!(MutSolubleWallMaze::add_wall(&mut maze, InteriorWall::new(0, 1, Vertical).unwrap()).is_err());
+    assert!(WallMazeTrait::remove_interior_wall(&mut maze, wall).is_ok());
+    assert!(WallMazeTrait::remove_wall(&mut maze, wall).is_err());
+}
+
+#[test]
+fn test_trait_move_start_end() {
+    let mut maze = example_maze();
+    let new_end = InteriorPosition::new(3, 3).unwrap();
+    assert!(MutSolubleMaze::move_end(&mut maze, new_end).is_ok());
+    assert_eq!(Maze::end(&maze), new_end);
+
+    MutSolubleMaze::flip_start_end(&mut maze);
+    assert_eq!(Maze::start(&maze), new_end);
+    assert_eq!(Maze::end(&maze), InteriorPosition::new(0, 0).unwrap());
+}
+
+#[test]
+fn test_are_adjacent() {
+    let pos = InteriorPosition::<3, 3>::new(1, 1).unwrap();
+    assert!(crate::are_adjacent(&pos, &InteriorPosition::new(1, 2).unwrap()));
+    assert!(!crate::are_adjacent(&pos, &InteriorPosition::new(2, 2).unwrap()));
+}
+
+#[test]
+fn test_passable_neighbours() {
+    let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    let mut maze = WallMaze::<3, 3>::new(start, end).unwrap();
+    maze.add_wall(Wall::new(1, 1, Horizontal)).unwrap();
+
+    let centre = InteriorPosition::new(1, 1).unwrap();
+    let below = InteriorPosition::new(1, 2).unwrap();
+    let neighbours: Vec<_> = maze.passable_neighbours(&centre).collect();
+    assert_eq!(neighbours.len(), 3);
+    assert!(!neighbours.contains(&below));
+
+    assert!(crate::are_passable_neighbours(&maze, &centre, &InteriorPosition::new(0, 1).unwrap()));
+    assert!(!crate::are_passable_neighbours(&maze, &centre, &below));
+    assert!(!crate::are_passable_neighbours(&maze, &centre, &end));
+}
+
+#[test]
+fn test_solve_returns_shortest_path() {
+    use crate::rng::{MazeRng, Xoshiro256StarStar};
+
+    let start = InteriorPosition::<8, 8>::new(0, 0).unwrap();
+    let end = InteriorPosition::<8, 8>::new(7, 7).unwrap();
+    let mut rng = Xoshiro256StarStar::new(5);
+    for _ in 0..20 {
+        let mut maze = WallMaze::<8, 8>::new(start, end).unwrap();
+        for _ in 0..80 {
+            let orientation = if rng.chance(0.5) { Horizontal } else { Vertical };
+            let _ = maze.add_wall(Wall::new(rng.below(8), rng.below(8), orientation));
+        }
+        let path = maze.solve().unwrap();
+        assert_valid_path(&maze, &path);
+        assert_eq!(Some(path.len()), Maze::try_solve_shortest(&maze).map(|path| path.len()));
+    }
+}
+
+#[test]
+fn test_solve_large_maze() {
+    const SIZE: usize = 1000;
+    let start = InteriorPosition::<SIZE, SIZE>::new(0, 0).unwrap();
+    let end = InteriorPosition::<SIZE, SIZE>::new(SIZE - 1, SIZE - 1).unwrap();
+    let mut maze = WallMaze::<SIZE, SIZE>::new(start, end).unwrap();
+
+    // A long barrier with a single gap at the far end forces a detour.
+    for x in 0..SIZE - 1 {
+        maze.walls.insert(Wall::new(x, SIZE / 2, Horizontal));
+    }
+    let path = maze.solve().unwrap();
+    assert_eq!(path.len(), 2 * (SIZE - 1) + 1);
+    assert!(path.contains(&InteriorPosition::new(SIZE - 1, SIZE / 2).unwrap()));
+}
+
+#[test]
+fn test_incremental_solvability_matches_full_search() {
+    use crate::rng::{MazeRng, Xoshiro256StarStar};
+
+    let start = InteriorPosition::<6, 6>::new(0, 0).unwrap();
+    let end = InteriorPosition::<6, 6>::new(5, 5).unwrap();
+    let mut maze = WallMaze::<6, 6>::new(start, end).unwrap();
+    let mut rng = Xoshiro256StarStar::new(6);
+    for _ in 0..200 {
+        let orientation = if rng.chance(0.5) { Horizontal } else { Vertical };
+        let Ok(wall) = InteriorWall::new(rng.below(6), rng.below(6), orientation) else {
+            continue;
+        };
+        if maze.walls.contains(wall.wall) {
+            continue;
+        }
+
+        let mut unchecked = maze.clone();
+        unchecked.walls.insert(wall.wall);
+        let expected = Maze::try_solve_shortest(&unchecked).is_some();
+        assert_eq!(maze.add_interior_wall(wall).is_ok(), expected);
+        assert_eq!(maze.walls.contains(wall.wall), expected);
+
+        // Removing walls never breaks the guarantee, so exercise that path too.
+        if expected && rng.chance(0.2) {
+            maze.remove_wall(wall).unwrap();
+        }
+    }
+    assert!(Maze::try_solve_shortest(&maze).is_some());
+}
+
+#[test]
+fn test_build_maze_wall_by_wall() {
+    const SIZE: usize = 30;
+    let start = InteriorPosition::<SIZE, SIZE>::new(0, 0).unwrap();
+    let end = InteriorPosition::<SIZE, SIZE>::new(0, SIZE - 1).unwrap();
+    let mut maze = WallMaze::<SIZE, SIZE>::new(start, end).unwrap();
+
+    // A serpentine: every row is closed off from the next except at alternating ends.
+    for y in 0..SIZE - 1 {
+        let gap = if y.is_multiple_of(2) { SIZE - 1 } else { 0 };
+        for x in (0..SIZE).filter(|&x| x != gap) {
+            maze.add_wall(Wall::new(x, y, Horizontal)).unwrap();
+        }
+        assert!(maze.add_wall(Wall::new(gap, y, Horizontal)).is_err());
+    }
+    assert_eq!(maze.solve().unwrap().len(), SIZE * SIZE);
+}
+
+#[test]
+fn test_equality_ignores_known_path() {
+    let start = InteriorPosition::<3, 3>::new(0, 0).unwrap();
+    let end = InteriorPosition::<3, 3>::new(2, 2).unwrap();
+    let mut maze = WallMaze::<3, 3>::new(start, end).unwrap();
+    let wall = InteriorWall::new(1, 1, Vertical).unwrap();
+    maze.add_interior_wall(wall).unwrap();
+    maze.remove_wall(wall).unwrap();
+
+    assert_eq!(maze, WallMaze::new(start, end).unwrap());
+}
+
+#[test]
+fn test_equality_independent_of_wall_order() {
+    use std::hash::{BuildHasher, RandomState};
+
+    let start = InteriorPosition::<4, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<4, 4>::new(3, 3).unwrap();
+    let walls = vec![
+        InteriorWall::new(0, 0, Vertical).unwrap(),
+        InteriorWall::new(1, 2, Horizontal).unwrap(),
+        InteriorWall::new(2, 1, Vertical).unwrap(),
+    ];
+    let mut reversed = walls.clone();
+    reversed.reverse();
+
+    let maze1 = WallMaze::from_walls(start, end, walls).unwrap();
+    let maze2 = WallMaze::from_walls(start, end, reversed).unwrap();
+    assert_eq!(maze1, maze2);
+
+    let hasher = RandomState::new();
+    assert_eq!(hasher.hash_one(&maze1), hasher.hash_one(&maze2));
+}
+
+#[test]
+fn test_errors_distinguish_cases() {
+    let wall = |x, y, orientation| Wall { x, y, orientation };
+
+    assert_eq!(
+        InteriorPosition::<5, 5>::new(5, 2),
+        Err(MazeError::PositionOutOfBounds { x: 5, y: 2, width: 5, height: 5 })
+    );
+    assert_eq!(
+        InteriorWall::<5, 5>::new(4, 2, Vertical),
+        Err(MazeError::WallOutOfBounds { wall: wall(4, 2, Vertical), width: 5, height: 5 })
+    );
+    assert_eq!(
+        InteriorPosition::<5, 5>::new(0, 3).unwrap().shifted_by(Left),
+        Err(MazeError::MoveOutOfBounds { x: 0, y: 3, direction: Left, width: 5, height: 5 })
+    );
+
+    let mut maze = example_maze();
+    let existing = InteriorWall::new(0, 0, Vertical).unwrap();
+    assert_eq!(maze.add_interior_wall(existing), Err(MazeError::WallAlreadyExists { wall: wall(0, 0, Vertical) }));
+
+    // (0, 1) can only be left through the wall below it, so this closes off the start
+    let sealing = InteriorWall::new(0, 1, Horizontal).unwrap();
+    assert_eq!(maze.add_interior_wall(sealing), Err(MazeError::WallWouldMakeUnsolvable { wall: wall(0, 1, Horizontal) }));
+    assert_eq!(maze.remove_wall(sealing), Err(MazeError::WallNotFound { wall: wall(0, 1, Horizontal) }));
+
+    let start = InteriorPosition::<5, 5>::new(2, 2).unwrap();
+    assert_eq!(WallMaze::new(start, start), Err(MazeError::StartIsEnd { x: 2, y: 2 }));
+}
+
+#[test]
+fn test_error_is_std_error() {
+    let error: Box<dyn std::error::Error> = Box::new(InteriorPosition::<3, 3>::new(3, 0).unwrap_err());
+    assert_eq!(error.to_string(), "position (3, 0) is out of bounds for a maze of dimensions 3 × 3");
+}
+
+/// A 5 × 5 maze from (0, 0) to (4, 4) in which (2, 2) is walled in on all four sides.
+fn maze_with_enclosed_cell() -> WallMaze<5, 5> {
+    let start = InteriorPosition::new(0, 0).unwrap();
+    let end = InteriorPosition::new(4, 4).unwrap();
+    let walls = vec![
+        InteriorWall::new(1, 2, Vertical).unwrap(),
+        InteriorWall::new(2, 2, Vertical).unwrap(),
+        InteriorWall::new(2, 1, Horizontal).unwrap(),
+        InteriorWall::new(2, 2, Horizontal).unwrap(),
+    ];
+    WallMaze::from_walls(start, end, walls).unwrap()
+}
+
+#[test]
+fn test_move_start_end_into_enclosed_cell() {
+    let mut maze = maze_with_enclosed_cell();
+    let enclosed = InteriorPosition::new(2, 2).unwrap();
+
+    assert_eq!(maze.move_start(enclosed), Err(MazeError::PositionWouldMakeUnsolvable { x: 2, y: 2 }));
+    assert_eq!(maze.move_end(enclosed), Err(MazeError::PositionWouldMakeUnsolvable { x: 2, y: 2 }));
+    assert_eq!(maze.get_start(), InteriorPosition::new(0, 0).unwrap());
+    assert_eq!(maze.get_end(), InteriorPosition::new(4, 4).unwrap());
+    assert_valid_path(&maze, &maze.solve().unwrap());
+}
+
+#[test]
+fn test_move_start_end_within_enclosed_region() {
+    // The start and end share the bottom-right 2 × 2 room, which has no way out
+    let start = InteriorPosition::<5, 5>::new(3, 3).unwrap();
+    let end = InteriorPosition::<5, 5>::new(4, 4).unwrap();
+    let walls = vec![
+        InteriorWall::new(2, 3, Vertical).unwrap(),
+        InteriorWall::new(2, 4, Vertical).unwrap(),
+        InteriorWall::new(3, 2, Horizontal).unwrap(),
+        InteriorWall::new(4, 2, Horizontal).unwrap(),
+    ];
+    let mut maze = WallMaze::from_walls(start, end, walls).unwrap();
+
+    let outside = InteriorPosition::new(0, 0).unwrap();
+    assert!(maze.move_start(outside).is_err());
+    assert!(maze.move_end(outside).is_err());
+
+    let inside = InteriorPosition::new(4, 3).unwrap();
+    assert!(maze.move_start(inside).is_ok());
+    let path = maze.solve().unwrap();
+    assert_valid_path(&maze, &path);
+    assert_eq!(path.len(), 2);
+
+    assert!(maze.move_end(InteriorPosition::new(3, 4).unwrap()).is_ok());
+    assert_eq!(maze.solve().unwrap().len(), 3);
+}
+
+#[test]
+fn test_move_start_end_onto_each_other() {
+    let mut maze = maze_with_enclosed_cell();
+    let start = maze.get_start();
+    let end = maze.get_end();
+
+    assert_eq!(maze.move_start(end), Err(MazeError::StartIsEnd { x: 4, y: 4 }));
+    assert_eq!(maze.move_end(start), Err(MazeError::StartIsEnd { x: 0, y: 0 }));
+    assert_eq!(maze.move_start(start), Ok(()));
+    assert_eq!((maze.get_start(), maze.get_end()), (start, end));
+}
+
+#[test]
+fn test_walls_after_moving_start() {
+    let mut maze = maze_with_enclosed_cell();
+    let new_start = InteriorPosition::new(4, 0).unwrap();
+    maze.move_start(new_start).unwrap();
+
+    // Sealing the new start in must be refused even though the old start is still open
+    assert!(maze.add_interior_wall(InteriorWall::new(3, 0, Vertical).unwrap()).is_ok());
+    assert!(maze.add_interior_wall(InteriorWall::new(4, 0, Horizontal).unwrap()).is_err());
+    assert_valid_path(&maze, &maze.solve().unwrap());
+}
+
+#[test]
+fn test_all_interior_walls() {
+    let walls: Vec<_> = InteriorWall::<4, 3>::all().collect();
+    assert_eq!(walls.len(), 4 * 2 + 3 * 3);
+    assert_eq!(walls.iter().collect::<std::collections::HashSet<_>>().len(), walls.len());
+    for x in 0..4 {
+        for y in 0..3 {
+            for orientation in [Horizontal, Vertical] {
+                assert_eq!(InteriorWall::<4, 3>::new(x, y, orientation).is_ok(), walls.iter().any(|w| w.get_wall() == Wall::new(x, y, orientation)));
+            }
+        }
+    }
+
+    assert_eq!(InteriorWall::<1, 1>::all().count(), 0);
+    assert!(InteriorWall::<5, 1>::all().all(|w| w.get_orientation() == Vertical));
+}
+
+#[test]
+fn test_walls_and_passages_partition_all_walls() {
+    let maze = example_maze();
+    let walls: Vec<_> = maze.walls().collect();
+    let passages: Vec<_> = maze.passages().collect();
+
+    assert_eq!(walls.len(), 16);
+    assert_eq!(walls.len() + passages.len(), InteriorWall::<5, 5>::all().count());
+    assert!(walls.iter().all(|&wall| maze.has_wall(wall)));
+    for [cell1, cell2] in passages {
+        assert!(!cell1.separated_by_wall(cell2, &maze).unwrap());
+    }
+
+    // The walls are enough to rebuild the same maze
+    let rebuilt = WallMaze::from_walls(maze.get_start(), maze.get_end(), walls).unwrap();
+    assert_eq!(rebuilt, maze);
+    assert_eq!(rebuilt.walls().collect::<Vec<_>>(), maze.walls().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_walls_follow_all_order() {
+    let mut maze = WallMaze::<4, 4>::new(InteriorPosition::new(0, 0).unwrap(), InteriorPosition::new(3, 3).unwrap()).unwrap();
+    for wall in InteriorWall::all().collect::<Vec<_>>().into_iter().rev() {
+        let _ = maze.add_interior_wall(wall);
+    }
+
+    let expected: Vec<_> = InteriorWall::all().filter(|&w| maze.has_wall(w)).collect();
+    assert_eq!(maze.walls().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_open_neighbours() {
+    let maze = maze_with_enclosed_cell();
+    let pos = |x, y| InteriorPosition::<5, 5>::new(x, y).unwrap();
+
+    assert!(maze.open_neighbours(pos(2, 2)).is_empty());
+    assert_eq!(maze.open_neighbours(pos(1, 2)), vec![pos(0, 2), pos(1, 1), pos(1, 3)]);
+    assert_eq!(maze.open_neighbours(pos(0, 0)), vec![pos(1, 0), pos(0, 1)]);
+    for x in 0..5 {
+        for y in 0..5 {
+            let trait_neighbours: Vec<_> = maze.passable_neighbours(&pos(x, y)).collect();
+            assert_eq!(maze.open_neighbours(pos(x, y)), trait_neighbours);
+        }
+    }
+}
+
+#[test]
+fn test_from_walls_rejects_start_at_end() {
+    let pos = InteriorPosition::<4, 4>::new(1, 2).unwrap();
+    let walls = vec![InteriorWall::new(0, 0, Vertical).unwrap()];
+    assert_eq!(WallMaze::from_walls(pos, pos, walls), Err(MazeError::StartIsEnd { x: 1, y: 2 }));
+}
+
+fn dead_end_count<const WIDTH: usize, const HEIGHT: usize>(maze: &WallMaze<WIDTH, HEIGHT>) -> usize {
+    (0..HEIGHT)
+        .flat_map(|y| (0..WIDTH).map(move |x| InteriorPosition::<WIDTH, HEIGHT>::new(x, y).unwrap()))
+        .filter(|&pos| maze.open_neighbours(pos).len() == 1)
+        .count()
+}
+
+fn perfect_maze(seed: u64) -> WallMaze<12, 9> {
+    let start = InteriorPosition::new(0, 0).unwrap();
+    let end = InteriorPosition::new(11, 8).unwrap();
+    crate::generate::recursive_backtracker(start, end, &mut crate::rng::Xoshiro256StarStar::new(seed)).unwrap()
+}
+
+#[test]
+fn test_braid() {
+    use crate::rng::Xoshiro256StarStar;
+
+    for seed in 0..10 {
+        let perfect = perfect_maze(seed);
+        let mut rng = Xoshiro256StarStar::new(seed);
+
+        let mut maze = perfect.clone();
+        assert_eq!(maze.braid(0.0, &mut rng), 0);
+        assert_eq!(maze, perfect);
+
+        let mut maze = perfect.clone();
+        let removed = maze.braid(1.0, &mut rng);
+        assert_eq!(dead_end_count(&maze), 0);
+        assert_eq!(maze.walls().count() + removed, perfect.walls().count());
+        assert!(maze.walls().all(|wall| perfect.has_wall(wall)));
+        assert_valid_path(&maze, &maze.solve().unwrap());
+    }
+
+    // Braiding more often leaves fewer dead ends
+    let counts: Vec<usize> = [0.0, 0.25, 0.5, 0.75, 1.0].into_iter().map(|probability| {
+        (0..20).map(|seed| {
+            let mut maze = perfect_maze(seed);
+            maze.braid(probability, &mut Xoshiro256StarStar::new(seed));
+            dead_end_count(&maze)
+        }).sum()
+    }).collect();
+    assert!(counts.windows(2).all(|pair| pair[0] > pair[1]), "{counts:?}");
+}
+
+#[test]
+fn test_braid_corridor() {
+    // The ends of a corridor one position wide have nowhere else to open up to
+    let start = InteriorPosition::<6, 1>::new(0, 0).unwrap();
+    let end = InteriorPosition::<6, 1>::new(5, 0).unwrap();
+    let mut maze = WallMaze::new(start, end).unwrap();
+    assert_eq!(maze.braid(1.0, &mut crate::rng::Xoshiro256StarStar::new(0)), 0);
+    assert_eq!(dead_end_count(&maze), 2);
+}
+
+#[test]
+fn test_unbraid() {
+    use crate::rng::Xoshiro256StarStar;
+
+    for seed in 0..10 {
+        let mut rng = Xoshiro256StarStar::new(seed);
+
+        let perfect = perfect_maze(seed);
+        let mut maze = perfect.clone();
+        assert_eq!(maze.unbraid(&mut rng), 0);
+        assert_eq!(maze, perfect);
+
+        let removed = maze.braid(0.6, &mut rng);
+        assert_eq!(maze.unbraid(&mut rng), removed);
+        assert_eq!(maze.passages().count(), 12 * 9 - 1);
+        assert_valid_path(&maze, &maze.solve().unwrap());
+        for pos in InteriorWall::<12, 9>::all().flat_map(|wall| wall.surrounding_cells()) {
+            let mut reachable = maze.clone();
+            assert!(reachable.move_end(pos).is_ok() || pos == maze.get_start());
+        }
+    }
+}
+
+#[test]
+fn test_unbraid_keeps_enclosed_cells_apart() {
+    let mut maze = maze_with_enclosed_cell();
+    let enclosed = InteriorPosition::<5, 5>::new(2, 2).unwrap();
+    maze.unbraid(&mut crate::rng::Xoshiro256StarStar::new(3));
+
+    // The 24 open positions form a tree, and the enclosed position stays cut off
+    assert_eq!(maze.passages().count(), 23);
+    assert!(maze.open_neighbours(enclosed).is_empty());
+    assert_valid_path(&maze, &maze.solve().unwrap());
+}
+
+#[test]
+fn test_shortest_solver_matches_exhaustive_search() {
+    use crate::rng::{MazeRng, Xoshiro256StarStar};
+
+    let mut rng = Xoshiro256StarStar::new(21);
+    for _ in 0..200 {
+        let start = InteriorPosition::<4, 4>::new(rng.below(4), rng.below(4)).unwrap();
+        let end = InteriorPosition::<4, 4>::new(rng.below(4), rng.below(4)).unwrap();
+        if start == end {
+            continue;
+        }
+        let mut maze = WallMaze::new(start, end).unwrap();
+        let walls: Vec<_> = InteriorWall::<4, 4>::all().collect();
+        let wall_count = rng.below(walls.len());
+        for _ in 0..wall_count {
+            let _ = maze.add_interior_wall(*rng.choose(&walls).unwrap());
+        }
+
+        let path = maze.solve_shortest().unwrap();
+        assert_valid_path(&maze, &path);
+        assert_eq!(Some(path.len() - 1), exhaustive_shortest_steps(&maze));
+    }
+}
+
+#[test]
+fn test_shortest_solver_on_braided_mazes() {
+    use crate::rng::Xoshiro256StarStar;
+
+    let mut rng = Xoshiro256StarStar::new(22);
+    let start = InteriorPosition::<5, 4>::new(0, 0).unwrap();
+    let end = InteriorPosition::<5, 4>::new(4, 3).unwrap();
+    for probability in [0.0, 0.3, 0.6, 1.0] {
+        for _ in 0..10 {
+            let mut maze = crate::generate::kruskal(start, end, &mut rng).unwrap();
+            maze.braid(probability, &mut rng);
+
+            let path = Maze::try_solve_shortest(&maze).unwrap();
+            assert_valid_path(&maze, &path);
+            assert_eq!(Some(path.len() - 1), exhaustive_shortest_steps(&maze));
+            assert_eq!(path.len(), maze.solve().unwrap().len());
+
+            let dyn_path = DynWallMaze::from(maze.clone()).solve_shortest().unwrap();
+            assert_eq!(dyn_path.len(), path.len());
+        }
+    }
+}
+
+#[test]
+fn test_solve_matches_a_star_solver() {
+    use crate::rng::Xoshiro256StarStar;
+    use crate::solve::{AStar, Solver};
+
+    let mut rng = Xoshiro256StarStar::new(22);
+    let start = InteriorPosition::<12, 12>::new(0, 0).unwrap();
+    let end = InteriorPosition::<12, 12>::new(11, 11).unwrap();
+    for probability in [0.0, 0.5, 1.0] {
+        for _ in 0..10 {
+            let mut maze = crate::generate::kruskal(start, end, &mut rng).unwrap();
+            maze.braid(probability, &mut rng);
+            assert_eq!(maze.solve().ok(), AStar.solve(&maze).path);
+
+            let dyn_maze = DynWallMaze::from(maze);
+            assert_eq!(dyn_maze.solve().ok(), AStar.solve(&dyn_maze).path);
+        }
+    }
+}
+
+/// Checks that the events of a search hang together: each snapshot of the frontier comes after
+/// the start or an expanded position and its discoveries, each discovery is a neighbour of the
+/// position just expanded, and each expanded position is the first of the frontier before it.
+fn assert_consistent_steps<const WIDTH: usize, const HEIGHT: usize>(maze: &WallMaze<WIDTH, HEIGHT>) -> Vec<SolveEvent<InteriorPosition<WIDTH, HEIGHT>>> {
+    let events: Vec<_> = maze.solve_steps().collect();
+    assert_eq!(events[0], SolveEvent::Discovered(maze.get_start()));
+    let mut expanding = None;
+    let mut frontier = None;
+    for event in &events[1..] {
+        match event {
+            SolveEvent::Discovered(pos) => {
+                assert!(maze.open_neighbours(expanding.unwrap()).contains(pos));
+            },
+            SolveEvent::Expanded(pos) => {
+                let frontier: &Vec<_> = frontier.take().unwrap();
+                assert_eq!(frontier.first(), Some(pos));
+                expanding = Some(*pos);
+            },
+            SolveEvent::Frontier(cells) => {
+                assert!(frontier.is_none());
+                frontier = Some(cells);
+            },
+            SolveEvent::Found(path) => {
+                assert_eq!(frontier.unwrap().first(), Some(&maze.get_end()));
+                assert_eq!(path.last(), Some(&maze.get_end()));
+            },
+            SolveEvent::Failed => panic!("a wall maze is always solvable"),
+        }
+    }
+    events
+}
+
+#[test]
+fn test_solve_steps_match_solve() {
+    use crate::rng::Xoshiro256StarStar;
+
+    let mut rng = Xoshiro256StarStar::new(24);
+    let start = InteriorPosition::<8, 6>::new(0, 2).unwrap();
+    let end = InteriorPosition::<8, 6>::new(7, 5).unwrap();
+    for probability in [0.0, 0.5, 1.0] {
+        for _ in 0..5 {
+            let mut maze = crate::generate::wilson(start, end, &mut rng).unwrap();
+            maze.braid(probability, &mut rng);
+
+            let events = assert_consistent_steps(&maze);
+            assert_eq!(events.last(), Some(&SolveEvent::Found(maze.solve().unwrap())));
+            assert_eq!(events, crate::solve::AStar::steps(&maze).collect::<Vec<_>>());
+
+            let dyn_events: Vec<_> = DynWallMaze::from(maze.clone()).solve_steps().collect();
+            assert_eq!(dyn_events.len(), events.len());
+            assert_eq!(dyn_events.last(), Some(&SolveEvent::Found(DynWallMaze::from(maze).solve().unwrap())));
+        }
+    }
+
+    // In an empty maze the search heads straight for the end
+    let maze = WallMaze::new(start, end).unwrap();
+    let expanded: Vec<_> = assert_consistent_steps(&maze).into_iter()
+        .filter_map(|event| if let SolveEvent::Expanded(pos) = event { Some(pos) } else { None })
+        .collect();
+    assert_eq!(expanded.len(), start.min_distance(end));
+}
+
+#[test]
+fn test_solve_with_stats() {
+    use crate::rng::Xoshiro256StarStar;
+
+    let mut rng = Xoshiro256StarStar::new(25);
+    let start = InteriorPosition::<8, 6>::new(0, 2).unwrap();
+    let end = InteriorPosition::<8, 6>::new(7, 5).unwrap();
+    for probability in [0.0, 0.5, 1.0] {
+        let mut maze = crate::generate::wilson(start, end, &mut rng).unwrap();
+        maze.braid(probability, &mut rng);
+
+        let solution = maze.solve_with_stats();
+        let path = maze.solve().unwrap();
+        assert_eq!(solution.stats.path_length, Some(path.len() - 1));
+        assert_eq!(solution.path, Some(path));
+
+        // The counts agree with the steps the search takes
+        let events: Vec<_> = maze.solve_steps().collect();
+        let expanded = events.iter().filter(|event| matches!(event, SolveEvent::Expanded(_))).count();
+        let discovered: std::collections::HashSet<_> = events.iter()
+            .filter_map(|event| if let SolveEvent::Discovered(pos) = event { Some(*pos) } else { None })
+            .collect();
+        assert_eq!(solution.stats.expanded, expanded);
+        assert_eq!(solution.stats.discovered, discovered.len());
+        let largest_frontier = events.iter()
+            .filter_map(|event| if let SolveEvent::Frontier(cells) = event { Some(cells.len()) } else { None })
+            .max();
+        assert_eq!(Some(solution.stats.peak_frontier), largest_frontier);
+
+        // The comparison table measures the same search
+        let row = *crate::solve::compare(&maze).get("a-star").unwrap();
+        assert_eq!(SolverStats { elapsed: solution.stats.elapsed, ..row }, solution.stats);
+
+        let dyn_solution = DynWallMaze::from(maze).solve_with_stats();
+        assert_eq!(dyn_solution.path.map(|path| path.len()), solution.path.map(|path| path.len()));
+        assert_eq!(dyn_solution.stats.expanded, solution.stats.expanded);
+        assert_eq!(dyn_solution.stats.discovered, solution.stats.discovered);
+        assert_eq!(dyn_solution.stats.peak_frontier, solution.stats.peak_frontier);
+    }
+}
@@ -0,0 +1,123 @@
+//! Compact storage for the walls of a maze, shared by the const-generic and runtime-sized wall mazes.
+
+use super::{Orientation, Wall};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of walls for a `width` × `height` grid, stored as one bit per possible wall.
+///
+/// Horizontal and vertical walls are kept in separate bit grids, each indexed by the
+/// (x, y) coordinate of the cell the wall belongs to, so membership tests, insertions and
+/// removals are all constant time. Two sets containing the same walls compare and hash
+/// equal regardless of the order in which the walls were added.
+///
+/// The set does not check that walls are within the interior of the grid; that is left to
+/// `InteriorWall` and `DynWall`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct WallSet {
+    width: usize,
+    horizontal: Vec<u64>,
+    vertical: Vec<u64>,
+}
+
+impl WallSet {
+    /// Creates an empty set of walls for a `width` × `height` grid.
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        let words = (width * height).div_ceil(BITS);
+        Self {
+            width,
+            horizontal: vec![0; words],
+            vertical: vec![0; words],
+        }
+    }
+
+    /// Returns the word index and mask of the bit for a wall within its orientation's bit grid.
+    fn bit(&self, wall: Wall) -> (usize, u64) {
+        let index = wall.y * self.width + wall.x;
+        (index / BITS, 1 << (index % BITS))
+    }
+
+    fn grid(&self, orientation: Orientation) -> &Vec<u64> {
+        match orientation {
+            Orientation::Horizontal => &self.horizontal,
+            Orientation::Vertical => &self.vertical,
+        }
+    }
+
+    fn grid_mut(&mut self, orientation: Orientation) -> &mut Vec<u64> {
+        match orientation {
+            Orientation::Horizontal => &mut self.horizontal,
+            Orientation::Vertical => &mut self.vertical,
+        }
+    }
+
+    /// Returns `true` if the wall is in the set.
+    pub(crate) fn contains(&self, wall: Wall) -> bool {
+        let (word, mask) = self.bit(wall);
+        self.grid(wall.orientation)[word] & mask != 0
+    }
+
+    /// Returns `true` if a wall lies between the adjacent cells at `(x1, y1)` and `(x2, y2)`.
+    pub(crate) fn separates(&self, (x1, y1): (usize, usize), (x2, y2): (usize, usize)) -> bool {
+        let wall = if y1 == y2 {
+            Wall { x: x1.min(x2), y: y1, orientation: Orientation::Vertical }
+        } else {
+            Wall { x: x1, y: y1.min(y2), orientation: Orientation::Horizontal }
+        };
+        self.contains(wall)
+    }
+
+    /// Iterates over the cells next to `(x, y)` in a grid `height` cells tall that no wall of the
+    /// set separates it from, in the order left, right, up, down.
+    pub(crate) fn open_neighbours(&self, (x, y): (usize, usize), height: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let neighbours = [
+            (x > 0).then(|| (x - 1, y)),
+            (x + 1 < self.width).then_some((x + 1, y)),
+            (y > 0).then(|| (x, y - 1)),
+            (y + 1 < height).then_some((x, y + 1)),
+        ];
+        neighbours.into_iter().flatten().filter(move |&adj| !self.separates((x, y), adj))
+    }
+
+    /// Adds a wall to the set, returning `false` if it was already present.
+    pub(crate) fn insert(&mut self, wall: Wall) -> bool {
+        let (word, mask) = self.bit(wall);
+        let grid = self.grid_mut(wall.orientation);
+        let inserted = grid[word] & mask == 0;
+        grid[word] |= mask;
+        inserted
+    }
+
+    /// Removes a wall from the set, returning `false` if it was not present.
+    pub(crate) fn remove(&mut self, wall: Wall) -> bool {
+        let (word, mask) = self.bit(wall);
+        let grid = self.grid_mut(wall.orientation);
+        let removed = grid[word] & mask != 0;
+        grid[word] &= !mask;
+        removed
+    }
+
+    /// Iterates over the walls in the set, in the order given by `interior_walls`.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = Wall> {
+        [Orientation::Horizontal, Orientation::Vertical].into_iter().flat_map(move |orientation| {
+            self.grid(orientation).iter().enumerate().flat_map(move |(word_index, &word)| {
+                (0..BITS).filter(move |bit| word & (1 << bit) != 0).map(move |bit| {
+                    let index = word_index * BITS + bit;
+                    Wall { x: index % self.width, y: index / self.width, orientation }
+                })
+            })
+        })
+    }
+}
+
+/// Iterates over every wall in the interior of a `width` × `height` grid.
+///
+/// The horizontal walls come first, then the vertical walls, each in row-major order
+/// of the cell they belong to.
+pub(crate) fn interior_walls(width: usize, height: usize) -> impl Iterator<Item = Wall> {
+    let horizontal = (0..height.saturating_sub(1))
+        .flat_map(move |y| (0..width).map(move |x| Wall { x, y, orientation: Orientation::Horizontal }));
+    let vertical = (0..height)
+        .flat_map(move |y| (0..width.saturating_sub(1)).map(move |x| Wall { x, y, orientation: Orientation::Vertical }));
+    horizontal.chain(vertical)
+}